@@ -0,0 +1,275 @@
+//! Hungarian-algorithm zone assignment for multi-robot fleets
+//!
+//! Clusters remaining dirty cells into target regions and solves the
+//! resulting assignment problem optimally so total fleet travel is
+//! minimized, rather than letting robots greedily pick targets.
+
+use crate::environment::Environment;
+use crate::robot::Robot;
+use crate::types::Position;
+use ordered_float::OrderedFloat;
+use std::collections::VecDeque;
+
+/// A contiguous cluster of dirty, reachable cells
+#[derive(Debug, Clone)]
+pub struct Zone {
+    pub cells: Vec<(usize, usize)>,
+    pub centroid: Position,
+}
+
+/// Robot -> zone assignment produced by the Hungarian solver. `None` means
+/// there were more robots than zones and this robot has nothing to do.
+#[derive(Debug, Clone, Default)]
+pub struct ZoneAssignment {
+    pub robot_to_zone: Vec<Option<usize>>,
+}
+
+/// Sentinel cost used to pad a non-square cost matrix so real robots/zones
+/// are never matched to a dummy counterpart in preference to a real one
+const SENTINEL_COST: f64 = 1e6;
+
+/// Cluster the environment's remaining dirty, free cells into contiguous
+/// target regions via a flood-fill connected-components pass
+pub fn cluster_dirty_regions(environment: &Environment) -> Vec<Zone> {
+    let mut visited = vec![vec![false; environment.width]; environment.height];
+    let mut zones = Vec::new();
+
+    for y in 0..environment.height {
+        for x in 0..environment.width {
+            if visited[y][x] || !environment.is_dirty(x, y) || !environment.is_valid_position(x, y) {
+                continue;
+            }
+
+            let mut cells = Vec::new();
+            let mut queue = VecDeque::new();
+            queue.push_back((x, y));
+            visited[y][x] = true;
+
+            while let Some((cx, cy)) = queue.pop_front() {
+                cells.push((cx, cy));
+
+                for (dx, dy) in [(0i32, 1i32), (0, -1), (1, 0), (-1, 0)] {
+                    let nx = cx as i32 + dx;
+                    let ny = cy as i32 + dy;
+                    if nx < 0 || ny < 0 {
+                        continue;
+                    }
+                    let (nx, ny) = (nx as usize, ny as usize);
+                    if nx >= environment.width || ny >= environment.height || visited[ny][nx] {
+                        continue;
+                    }
+                    if environment.is_dirty(nx, ny) && environment.is_valid_position(nx, ny) {
+                        visited[ny][nx] = true;
+                        queue.push_back((nx, ny));
+                    }
+                }
+            }
+
+            let count = cells.len() as f64;
+            let (sum_x, sum_y) = cells.iter().fold((0.0, 0.0), |(sx, sy), &(cx, cy)| {
+                (sx + cx as f64, sy + cy as f64)
+            });
+            zones.push(Zone {
+                cells,
+                centroid: Position::new(sum_x / count, sum_y / count),
+            });
+        }
+    }
+
+    zones
+}
+
+/// Optimally assign fleet robots to dirty-region zones by solving the
+/// Hungarian (Kuhn-Munkres) assignment problem on a robot-by-zone Manhattan
+/// distance cost matrix. Zones are re-clustered from the current dirt on every
+/// call; each call solves once.
+pub fn assign_zones(robots: &[Robot], environment: &Environment) -> ZoneAssignment {
+    let zones = cluster_dirty_regions(environment);
+    assign_to_zones(robots, &zones)
+}
+
+fn assign_to_zones(robots: &[Robot], zones: &[Zone]) -> ZoneAssignment {
+    let n = robots.len();
+    let m = zones.len();
+
+    if n == 0 || m == 0 {
+        return ZoneAssignment {
+            robot_to_zone: vec![None; n],
+        };
+    }
+
+    let size = n.max(m);
+    let mut cost = vec![vec![SENTINEL_COST; size]; size];
+
+    for (i, robot) in robots.iter().enumerate() {
+        for (j, zone) in zones.iter().enumerate() {
+            cost[i][j] = robot.position.manhattan_distance(&zone.centroid);
+        }
+    }
+
+    let assignment = solve_hungarian(&cost);
+
+    let robot_to_zone = (0..n)
+        .map(|i| {
+            let j = assignment[i];
+            if j < m {
+                Some(j)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    ZoneAssignment { robot_to_zone }
+}
+
+/// Solve the square minimum-cost assignment problem via the Hungarian
+/// algorithm in its row/column-potential, shortest-augmenting-path form:
+/// each row is added in turn and matched along the cheapest augmenting path,
+/// with the potentials adjusted by the smallest reduced cost found. The
+/// explicit row/column-minima reduction and minimum line cover over zeros are
+/// not performed. Returns `result[row] = column`.
+fn solve_hungarian(cost: &[Vec<f64>]) -> Vec<usize> {
+    let n = cost.len();
+    const INF: f64 = f64::INFINITY;
+
+    // 1-indexed potentials/assignment, following the standard shortest-augmenting-path
+    // formulation of the Hungarian algorithm.
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; n + 1];
+    let mut p = vec![0usize; n + 1]; // p[j] = row currently assigned to column j
+    let mut way = vec![0usize; n + 1];
+
+    for i in 1..=n {
+        p[0] = i;
+        let mut j0 = 0usize;
+        let mut minv = vec![OrderedFloat(INF); n + 1];
+        let mut used = vec![false; n + 1];
+
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = OrderedFloat(INF);
+            let mut j1 = 0usize;
+
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+                let cur = OrderedFloat(cost[i0 - 1][j - 1] - u[i0] - v[j]);
+                if cur < minv[j] {
+                    minv[j] = cur;
+                    way[j] = j0;
+                }
+                if minv[j] < delta {
+                    delta = minv[j];
+                    j1 = j;
+                }
+            }
+
+            for j in 0..=n {
+                if used[j] {
+                    u[p[j]] += delta.into_inner();
+                    v[j] -= delta.into_inner();
+                } else {
+                    minv[j] = OrderedFloat(minv[j].into_inner() - delta.into_inner());
+                }
+            }
+
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+
+        loop {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+            if j0 == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut result = vec![0usize; n];
+    for j in 1..=n {
+        if p[j] > 0 {
+            result[p[j] - 1] = j - 1;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cluster_dirty_regions_splits_disconnected_areas() {
+        let mut env = Environment::create_empty_room(10, 5);
+        // Clean everything, then re-dirty two separated single cells
+        for x in 0..10 {
+            for y in 0..5 {
+                env.clean_cell(x, y, 1.0);
+            }
+        }
+        env.set_dirt(1, 1, 1.0);
+        env.set_dirt(7, 3, 1.0);
+
+        let zones = cluster_dirty_regions(&env);
+        assert_eq!(zones.len(), 2);
+        assert_eq!(zones[0].cells.len(), 1);
+        assert_eq!(zones[1].cells.len(), 1);
+    }
+
+    #[test]
+    fn test_solve_hungarian_picks_minimum_cost_assignment() {
+        // Robot 0 is closest to zone 1, robot 1 is closest to zone 0
+        let cost = vec![vec![10.0, 1.0], vec![1.0, 10.0]];
+        let assignment = solve_hungarian(&cost);
+
+        assert_eq!(assignment[0], 1);
+        assert_eq!(assignment[1], 0);
+    }
+
+    #[test]
+    fn test_assign_zones_matches_nearest_robot() {
+        let mut env = Environment::create_empty_room(20, 20);
+        for x in 0..20 {
+            for y in 0..20 {
+                env.clean_cell(x, y, 1.0);
+            }
+        }
+        env.set_dirt(2, 2, 1.0);
+        env.set_dirt(17, 17, 1.0);
+
+        let robot_near_first = Robot::new(Position::new(2.0, 2.0));
+        let robot_near_second = Robot::new(Position::new(17.0, 17.0));
+        let robots = vec![robot_near_first, robot_near_second];
+
+        let assignment = assign_zones(&robots, &env);
+
+        assert_ne!(assignment.robot_to_zone[0], assignment.robot_to_zone[1]);
+        // Zones are found in row-major order: zone 0 at (2, 2), zone 1 at (17, 17)
+        assert_eq!(assignment.robot_to_zone[0], Some(0));
+        assert_eq!(assignment.robot_to_zone[1], Some(1));
+    }
+
+    #[test]
+    fn test_assign_zones_handles_more_robots_than_zones() {
+        let mut env = Environment::create_empty_room(10, 10);
+        for x in 0..10 {
+            for y in 0..10 {
+                env.clean_cell(x, y, 1.0);
+            }
+        }
+        env.set_dirt(5, 5, 1.0);
+
+        let robots = vec![Robot::new(Position::new(1.0, 1.0)), Robot::new(Position::new(8.0, 8.0))];
+        let assignment = assign_zones(&robots, &env);
+
+        let assigned_count = assignment.robot_to_zone.iter().filter(|z| z.is_some()).count();
+        assert_eq!(assigned_count, 1);
+    }
+}
@@ -0,0 +1,153 @@
+//! Dock-reachability and coverage-saturation analysis
+//!
+//! Answers "can the robot actually reach every free cell from its dock, and
+//! how long would that take in the worst case" before a run starts, by flood
+//! filling outward from `dock_position` one ring at a time.
+
+use crate::environment::{CellType, Environment};
+use std::collections::{HashSet, VecDeque};
+
+/// Result of a coverage-saturation flood fill from the dock
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    /// Number of free cells reachable from the dock
+    pub reachable: usize,
+    /// Total number of free cells in the environment
+    pub total_free: usize,
+    /// Free cells that are not reachable from the dock (e.g. behind a closed door)
+    pub unreachable: Vec<(usize, usize)>,
+    /// Number of BFS rings needed to saturate all reachable cells, i.e. the
+    /// lower bound on cell-steps to cover the whole reachable area
+    pub max_distance: usize,
+}
+
+/// Flood fill outward from `environment.dock_position` over `Free` cells,
+/// one ring per iteration, until no new cells are added
+pub fn coverage_analysis(environment: &Environment) -> CoverageReport {
+    let total_free = environment
+        .grid
+        .iter()
+        .filter(|&&cell| cell == CellType::Free as u8)
+        .count();
+
+    let Some(dock) = environment.dock_position else {
+        let unreachable = free_cells(environment);
+        return CoverageReport { reachable: 0, total_free, unreachable, max_distance: 0 };
+    };
+
+    let mut visited = HashSet::new();
+    visited.insert(dock);
+    let mut frontier = VecDeque::new();
+    frontier.push_back(dock);
+    let mut max_distance = 0;
+
+    while !frontier.is_empty() {
+        let mut next_frontier = VecDeque::new();
+
+        while let Some((x, y)) = frontier.pop_front() {
+            for (dx, dy) in [(0i32, 1i32), (0, -1), (1, 0), (-1, 0)] {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+                if nx < 0 || ny < 0 {
+                    continue;
+                }
+                let (nx, ny) = (nx as usize, ny as usize);
+                if visited.contains(&(nx, ny)) || !environment.is_valid_position(nx, ny) {
+                    continue;
+                }
+
+                visited.insert((nx, ny));
+                next_frontier.push_back((nx, ny));
+            }
+        }
+
+        if !next_frontier.is_empty() {
+            max_distance += 1;
+        }
+        frontier = next_frontier;
+    }
+
+    let unreachable = free_cells(environment)
+        .into_iter()
+        .filter(|pos| !visited.contains(pos))
+        .collect();
+
+    // `visited` also includes the dock cell itself, which is `CellType::Dock`
+    // (not `Free`) in the normal case, so it must not be counted here.
+    let reachable = visited
+        .iter()
+        .filter(|&&(x, y)| environment.grid[[y, x]] == CellType::Free as u8)
+        .count();
+
+    CoverageReport {
+        reachable,
+        total_free,
+        unreachable,
+        max_distance,
+    }
+}
+
+fn free_cells(environment: &Environment) -> Vec<(usize, usize)> {
+    environment
+        .grid
+        .indexed_iter()
+        .filter(|&(_, &cell)| cell == CellType::Free as u8)
+        .map(|((y, x), _)| (x, y))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array2;
+
+    #[test]
+    fn test_coverage_analysis_reaches_whole_open_room() {
+        let mut env = Environment::create_empty_room(10, 10);
+        env.dock_position = Some((5, 5));
+
+        let report = coverage_analysis(&env);
+
+        assert_eq!(report.reachable, report.total_free);
+        assert!(report.unreachable.is_empty());
+        assert!(report.max_distance > 0);
+    }
+
+    #[test]
+    fn test_coverage_analysis_detects_isolated_room() {
+        let mut grid = Array2::zeros((10, 10));
+        for x in 0..10 {
+            grid[[0, x]] = CellType::Obstacle as u8;
+            grid[[9, x]] = CellType::Obstacle as u8;
+        }
+        for y in 0..10 {
+            grid[[y, 0]] = CellType::Obstacle as u8;
+            grid[[y, 9]] = CellType::Obstacle as u8;
+        }
+        // Wall off a sealed side room with no doorway
+        for x in 1..9 {
+            grid[[5, x]] = CellType::Obstacle as u8;
+        }
+
+        let mut env = Environment::from_grid(grid);
+        env.dock_position = Some((4, 2));
+
+        let report = coverage_analysis(&env);
+
+        assert!(report.reachable < report.total_free);
+        assert!(!report.unreachable.is_empty());
+        for (_x, y) in &report.unreachable {
+            assert!(*y > 5);
+        }
+    }
+
+    #[test]
+    fn test_coverage_analysis_without_dock_reports_nothing_reachable() {
+        let env = Environment::create_empty_room(10, 10);
+        let report = coverage_analysis(&env);
+
+        assert_eq!(report.reachable, 0);
+        assert_eq!(report.unreachable.len(), report.total_free);
+        assert_eq!(report.max_distance, 0);
+    }
+}
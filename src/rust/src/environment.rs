@@ -1,8 +1,82 @@
 //! Environment simulation
 
+use crate::slam::DoubleBuffer;
+use crate::types::{Position, Velocity};
 use ndarray::Array2;
 use serde::{Deserialize, Serialize};
 
+/// Which edge of the grid a boundary condition applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BoundaryDirection {
+    North,
+    South,
+    East,
+    West,
+}
+
+/// Policy applied when a robot's trajectory crosses a grid edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BoundaryCondition {
+    /// Crossing the edge is a fatal error
+    Kill,
+    /// Bounce back in, flipping the outward velocity component (angle of incidence = angle of reflection)
+    Reflect,
+    /// Wrap around to the opposite edge (toroidal room)
+    Periodic,
+    /// Clamp to the edge and zero the outward velocity component
+    Absorb,
+}
+
+/// Per-edge boundary policy for an [`Environment`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Boundary {
+    pub north: BoundaryCondition,
+    pub south: BoundaryCondition,
+    pub east: BoundaryCondition,
+    pub west: BoundaryCondition,
+}
+
+impl Boundary {
+    /// Create a boundary with an explicit condition per edge
+    pub fn new(
+        north: BoundaryCondition,
+        south: BoundaryCondition,
+        east: BoundaryCondition,
+        west: BoundaryCondition,
+    ) -> Self {
+        Self { north, south, east, west }
+    }
+
+    /// Create a boundary with the same condition on all four edges
+    pub fn uniform(condition: BoundaryCondition) -> Self {
+        Self::new(condition, condition, condition, condition)
+    }
+
+    /// Condition for a given edge
+    pub fn condition(&self, direction: BoundaryDirection) -> BoundaryCondition {
+        match direction {
+            BoundaryDirection::North => self.north,
+            BoundaryDirection::South => self.south,
+            BoundaryDirection::East => self.east,
+            BoundaryDirection::West => self.west,
+        }
+    }
+}
+
+impl Default for Boundary {
+    fn default() -> Self {
+        Self::uniform(BoundaryCondition::Reflect)
+    }
+}
+
+/// Outcome of resolving a position/velocity against the environment's boundary
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundaryResolution {
+    pub position: Position,
+    pub velocity: Velocity,
+    pub killed: bool,
+}
+
 /// Cell types in the environment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[repr(u8)]
@@ -25,6 +99,12 @@ impl From<u8> for CellType {
     }
 }
 
+/// Dirt density regenerates on free cells at this rate per second of sim time
+const DEFAULT_REGENERATION_RATE: f64 = 0.002;
+
+/// Diffusion coefficient (kappa) for the discrete Laplacian dirt spread
+const DEFAULT_DIFFUSION_RATE: f64 = 0.05;
+
 /// Environment representation
 #[derive(Debug, Clone)]
 pub struct Environment {
@@ -32,30 +112,45 @@ pub struct Environment {
     pub width: usize,
     pub height: usize,
     pub dock_position: Option<(usize, usize)>,
-    pub dirty_map: Array2<bool>,
+    /// Continuous dirt density per cell in `[0, 1]`; 0 is spotless.
+    /// Double-buffered so `update_dirt_field`'s neighbor-Laplacian can read
+    /// last tick's values while writing this tick's without aliasing.
+    dirt: DoubleBuffer<f32>,
     pub sim_time: f64,
+    pub boundary: Boundary,
+    /// Dirt regenerated per second of sim time on free cells
+    pub regeneration_rate: f64,
+    /// Diffusion coefficient (kappa) spreading dirt to 4-neighbors each tick
+    pub diffusion_rate: f64,
+    /// Per-cell traversal cost multiplier (e.g. carpet costs more to cross
+    /// than hardwood); `1.0` is the baseline unit-cost floor
+    pub floor_cost: Array2<f32>,
 }
 
 impl Environment {
     /// Create a new environment
     pub fn new(width: usize, height: usize) -> Self {
         let grid = Array2::zeros((height, width));
-        let dirty_map = Array2::from_elem((height, width), true);
+        let dirt = DoubleBuffer::new((height, width), 1.0_f32);
 
         Self {
             grid,
             width,
             height,
             dock_position: None,
-            dirty_map,
+            dirt,
             sim_time: 0.0,
+            boundary: Boundary::default(),
+            regeneration_rate: DEFAULT_REGENERATION_RATE,
+            diffusion_rate: DEFAULT_DIFFUSION_RATE,
+            floor_cost: Array2::from_elem((height, width), 1.0_f32),
         }
     }
 
     /// Create environment from existing grid
     pub fn from_grid(grid: Array2<u8>) -> Self {
         let (height, width) = grid.dim();
-        let dirty_map = Array2::from_elem((height, width), true);
+        let dirt = DoubleBuffer::new((height, width), 1.0_f32);
 
         // Find dock position
         let mut dock_position = None;
@@ -71,8 +166,12 @@ impl Environment {
             width,
             height,
             dock_position,
-            dirty_map,
+            dirt,
             sim_time: 0.0,
+            boundary: Boundary::default(),
+            regeneration_rate: DEFAULT_REGENERATION_RATE,
+            diffusion_rate: DEFAULT_DIFFUSION_RATE,
+            floor_cost: Array2::from_elem((height, width), 1.0_f32),
         }
     }
 
@@ -104,49 +203,195 @@ impl Environment {
         matches!(cell_type, CellType::Free | CellType::Dock)
     }
 
-    /// Clean a cell
-    pub fn clean_cell(&mut self, x: usize, y: usize) {
+    /// Traversal cost multiplier for the cell at `(x, y)` (e.g. carpet vs.
+    /// hardwood); out-of-bounds cells cost the baseline `1.0`
+    pub fn cell_cost(&self, x: usize, y: usize) -> f64 {
+        if x < self.width && y < self.height {
+            self.floor_cost[[y, x]] as f64
+        } else {
+            1.0
+        }
+    }
+
+    /// Set the traversal cost multiplier for the cell at `(x, y)`
+    pub fn set_floor_cost(&mut self, x: usize, y: usize, cost: f64) {
+        if x < self.width && y < self.height {
+            self.floor_cost[[y, x]] = cost as f32;
+        }
+    }
+
+    /// Resolve a tentative (position, velocity) against the grid edges using the
+    /// environment's configured `Boundary`, applying the policy of whichever
+    /// edge(s) were crossed.
+    pub fn resolve_boundary(&self, position: Position, velocity: Velocity) -> BoundaryResolution {
+        let mut pos = position;
+        let mut vel = velocity;
+        let mut killed = false;
+
+        let max_x = self.width as f64;
+        let max_y = self.height as f64;
+
+        if pos.x < 0.0 {
+            let overshoot = -pos.x;
+            self.apply_edge(BoundaryDirection::West, &mut pos.x, &mut vel.vx, 0.0, overshoot, &mut killed);
+        } else if pos.x > max_x {
+            let overshoot = pos.x - max_x;
+            self.apply_edge(BoundaryDirection::East, &mut pos.x, &mut vel.vx, max_x, overshoot, &mut killed);
+        }
+
+        if pos.y < 0.0 {
+            let overshoot = -pos.y;
+            self.apply_edge(BoundaryDirection::North, &mut pos.y, &mut vel.vy, 0.0, overshoot, &mut killed);
+        } else if pos.y > max_y {
+            let overshoot = pos.y - max_y;
+            self.apply_edge(BoundaryDirection::South, &mut pos.y, &mut vel.vy, max_y, overshoot, &mut killed);
+        }
+
+        BoundaryResolution { position: pos, velocity: vel, killed }
+    }
+
+    /// Apply a single edge's boundary condition to one axis of position/velocity.
+    /// `edge` is the coordinate of the edge itself, `overshoot` is how far past it we went.
+    fn apply_edge(
+        &self,
+        direction: BoundaryDirection,
+        coord: &mut f64,
+        vel_component: &mut f64,
+        edge: f64,
+        overshoot: f64,
+        killed: &mut bool,
+    ) {
+        match self.boundary.condition(direction) {
+            BoundaryCondition::Kill => {
+                *killed = true;
+            }
+            BoundaryCondition::Reflect => {
+                // Angle of incidence = angle of reflection: bounce back in by the
+                // overshoot distance and flip the outward velocity component.
+                *coord = if edge == 0.0 { overshoot } else { edge - overshoot };
+                *vel_component = -*vel_component;
+            }
+            BoundaryCondition::Periodic => {
+                *coord = if edge == 0.0 {
+                    self.wrap_dimension(direction) - overshoot
+                } else {
+                    overshoot
+                };
+            }
+            BoundaryCondition::Absorb => {
+                *coord = edge;
+                *vel_component = 0.0;
+            }
+        }
+    }
+
+    /// The grid extent along the axis a given edge belongs to (for Periodic wrap)
+    fn wrap_dimension(&self, direction: BoundaryDirection) -> f64 {
+        match direction {
+            BoundaryDirection::North | BoundaryDirection::South => self.height as f64,
+            BoundaryDirection::East | BoundaryDirection::West => self.width as f64,
+        }
+    }
+
+    /// Current, stable-to-read dirt-density grid
+    pub fn dirt_density(&self) -> &Array2<f32> {
+        self.dirt.front()
+    }
+
+    /// Directly set a cell's dirt density, e.g. for test/scenario setup; use
+    /// `clean_cell` during simulation, which subtracts suction_efficiency
+    /// instead of overwriting
+    pub fn set_dirt(&mut self, x: usize, y: usize, value: f64) {
+        if x < self.width && y < self.height {
+            self.dirt.front_mut()[[y, x]] = value.clamp(0.0, 1.0) as f32;
+        }
+    }
+
+    /// Clean a cell, subtracting `suction_efficiency` from its dirt density
+    /// instead of zeroing it outright
+    pub fn clean_cell(&mut self, x: usize, y: usize, suction_efficiency: f64) {
         if x < self.width && y < self.height {
-            self.dirty_map[[y, x]] = false;
+            let remaining = self.dirt.front()[[y, x]] as f64 - suction_efficiency;
+            self.dirt.front_mut()[[y, x]] = remaining.clamp(0.0, 1.0) as f32;
         }
     }
 
-    /// Check if cell is dirty
+    /// Check if cell has any dirt left
     pub fn is_dirty(&self, x: usize, y: usize) -> bool {
         if x < self.width && y < self.height {
-            self.dirty_map[[y, x]]
+            self.dirt.front()[[y, x]] > 0.0
         } else {
             false
         }
     }
 
-    /// Get cleaning percentage
+    /// Get cleaning percentage: `(1 - mean(dirt over Free cells)) * 100`
     pub fn get_cleaning_percentage(&self) -> f64 {
-        let total_cleanable = self.grid.iter()
-            .filter(|&&cell| cell == CellType::Free as u8)
-            .count();
+        let free_cells: Vec<f32> = self.grid.indexed_iter()
+            .filter(|&(_, &cell)| cell == CellType::Free as u8)
+            .map(|((y, x), _)| self.dirt.front()[[y, x]])
+            .collect();
 
-        if total_cleanable == 0 {
+        if free_cells.is_empty() {
             return 100.0;
         }
 
-        let cleaned = self.grid.indexed_iter()
-            .filter(|&((y, x), &cell)| {
-                cell == CellType::Free as u8 && !self.dirty_map[[y, x]]
-            })
-            .count();
-
-        (cleaned as f64 / total_cleanable as f64) * 100.0
+        let mean_dirt = free_cells.iter().sum::<f32>() as f64 / free_cells.len() as f64;
+        (1.0 - mean_dirt) * 100.0
     }
 
-    /// Step simulation
+    /// Step simulation: advance sim time and evolve the dirt field by one tick
     pub fn step(&mut self, delta_time: f64) {
         self.sim_time += delta_time;
+        self.update_dirt_field(delta_time);
+    }
+
+    /// Regenerate dirt on free cells and diffuse it to neighbors via a
+    /// discrete Laplacian, reading the previous tick's snapshot so every
+    /// cell's update is computed from consistent last-tick state.
+    fn update_dirt_field(&mut self, delta_time: f64) {
+        // Seed the back buffer with this tick's starting state so cells we
+        // skip below (non-Free) carry their value over unchanged, then only
+        // overwrite the cells we actually recompute.
+        *self.dirt.back_mut() = self.dirt.front().clone();
+        let regen = self.regeneration_rate * delta_time;
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if CellType::from(self.grid[[y, x]]) != CellType::Free {
+                    continue;
+                }
+
+                let mut neighbor_sum = 0.0;
+                for (dx, dy) in [(0i32, 1i32), (0, -1), (1, 0), (-1, 0)] {
+                    let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                    if nx < 0 || ny < 0 {
+                        continue;
+                    }
+                    let (nx, ny) = (nx as usize, ny as usize);
+                    if nx >= self.width || ny >= self.height {
+                        continue;
+                    }
+                    if matches!(CellType::from(self.grid[[ny, nx]]), CellType::Obstacle | CellType::Cliff) {
+                        continue;
+                    }
+                    neighbor_sum += self.dirt.front()[[ny, nx]] as f64;
+                }
+
+                let current = self.dirt.front()[[y, x]] as f64;
+                let laplacian = neighbor_sum - 4.0 * current;
+                let next = current + self.diffusion_rate * laplacian + regen;
+
+                self.dirt.back_mut()[[y, x]] = next.clamp(0.0, 1.0) as f32;
+            }
+        }
+
+        self.dirt.switch();
     }
 
     /// Reset environment
     pub fn reset(&mut self) {
-        self.dirty_map.fill(true);
+        self.dirt.front_mut().fill(1.0);
         self.sim_time = 0.0;
     }
 }
@@ -173,6 +418,15 @@ mod tests {
         assert_eq!(env.grid[[15, 15]], CellType::Free as u8); // Center
     }
 
+    #[test]
+    fn test_floor_cost_defaults_to_unit_cost() {
+        let mut env = Environment::create_empty_room(10, 10);
+        assert_eq!(env.cell_cost(5, 5), 1.0);
+
+        env.set_floor_cost(5, 5, 2.5);
+        assert_eq!(env.cell_cost(5, 5), 2.5);
+    }
+
     #[test]
     fn test_is_valid_position() {
         let env = Environment::create_empty_room(30, 30);
@@ -186,10 +440,23 @@ mod tests {
         let mut env = Environment::create_empty_room(30, 30);
         assert!(env.is_dirty(15, 15));
 
-        env.clean_cell(15, 15);
+        env.clean_cell(15, 15, 1.0);
         assert!(!env.is_dirty(15, 15));
     }
 
+    #[test]
+    fn test_clean_cell_subtracts_suction_efficiency() {
+        let mut env = Environment::create_empty_room(30, 30);
+
+        env.clean_cell(15, 15, 0.3);
+        assert!((env.dirt_density()[[15, 15]] - 0.7).abs() < 1e-6);
+        assert!(env.is_dirty(15, 15));
+
+        // Clamps at zero rather than going negative
+        env.clean_cell(15, 15, 10.0);
+        assert_eq!(env.dirt_density()[[15, 15]], 0.0);
+    }
+
     #[test]
     fn test_cleaning_percentage() {
         let mut env = Environment::create_empty_room(30, 30);
@@ -200,7 +467,7 @@ mod tests {
         for x in 10..20 {
             for y in 10..20 {
                 if env.is_valid_position(x, y) {
-                    env.clean_cell(x, y);
+                    env.clean_cell(x, y, 1.0);
                 }
             }
         }
@@ -209,6 +476,53 @@ mod tests {
         assert!(final_pct > initial_pct);
     }
 
+    #[test]
+    fn test_dirt_regenerates_over_time() {
+        let mut env = Environment::create_empty_room(30, 30);
+        env.clean_cell(15, 15, 1.0);
+        assert_eq!(env.dirt_density()[[15, 15]], 0.0);
+
+        for _ in 0..100 {
+            env.step(1.0);
+        }
+
+        assert!(env.dirt_density()[[15, 15]] > 0.0);
+    }
+
+    #[test]
+    fn test_dirt_diffuses_from_dirty_neighbors() {
+        let mut env = Environment::create_empty_room(30, 30);
+        for x in 1..29 {
+            for y in 1..29 {
+                env.set_dirt(x, y, 0.0);
+            }
+        }
+        env.set_dirt(15, 15, 1.0);
+        env.regeneration_rate = 0.0;
+
+        env.step(1.0);
+
+        assert!(env.dirt_density()[[15, 14]] > 0.0);
+        assert!(env.dirt_density()[[15, 15]] < 1.0);
+    }
+
+    #[test]
+    fn test_dirt_does_not_diffuse_across_obstacles() {
+        let mut env = Environment::create_empty_room(30, 30);
+        for x in 1..29 {
+            for y in 1..29 {
+                env.set_dirt(x, y, 0.0);
+            }
+        }
+        env.grid[[15, 16]] = CellType::Obstacle as u8;
+        env.set_dirt(15, 15, 1.0);
+        env.regeneration_rate = 0.0;
+
+        env.step(1.0);
+
+        assert_eq!(env.dirt_density()[[15, 16]], 0.0);
+    }
+
     #[test]
     fn test_environment_step() {
         let mut env = Environment::new(30, 30);
@@ -219,7 +533,7 @@ mod tests {
     #[test]
     fn test_environment_reset() {
         let mut env = Environment::create_empty_room(30, 30);
-        env.clean_cell(15, 15);
+        env.clean_cell(15, 15, 1.0);
         env.sim_time = 100.0;
 
         env.reset();
@@ -228,6 +542,57 @@ mod tests {
         assert_eq!(env.sim_time, 0.0);
     }
 
+    #[test]
+    fn test_boundary_default_is_reflect() {
+        let env = Environment::new(30, 30);
+        assert_eq!(env.boundary.north, BoundaryCondition::Reflect);
+        assert_eq!(env.boundary.west, BoundaryCondition::Reflect);
+    }
+
+    #[test]
+    fn test_resolve_boundary_reflect() {
+        let mut env = Environment::new(30, 30);
+        env.boundary = Boundary::uniform(BoundaryCondition::Reflect);
+
+        let resolution = env.resolve_boundary(Position::new(-1.0, 10.0), Velocity::new(-2.0, 0.0));
+
+        assert_eq!(resolution.position.x, 1.0);
+        assert_eq!(resolution.velocity.vx, 2.0);
+        assert!(!resolution.killed);
+    }
+
+    #[test]
+    fn test_resolve_boundary_kill() {
+        let mut env = Environment::new(30, 30);
+        env.boundary = Boundary::uniform(BoundaryCondition::Kill);
+
+        let resolution = env.resolve_boundary(Position::new(31.0, 10.0), Velocity::new(2.0, 0.0));
+
+        assert!(resolution.killed);
+    }
+
+    #[test]
+    fn test_resolve_boundary_periodic() {
+        let mut env = Environment::new(30, 30);
+        env.boundary = Boundary::uniform(BoundaryCondition::Periodic);
+
+        let resolution = env.resolve_boundary(Position::new(-1.0, 10.0), Velocity::new(-2.0, 0.0));
+
+        assert_eq!(resolution.position.x, 29.0);
+        assert!(!resolution.killed);
+    }
+
+    #[test]
+    fn test_resolve_boundary_absorb() {
+        let mut env = Environment::new(30, 30);
+        env.boundary = Boundary::uniform(BoundaryCondition::Absorb);
+
+        let resolution = env.resolve_boundary(Position::new(31.0, 10.0), Velocity::new(2.0, 0.0));
+
+        assert_eq!(resolution.position.x, 30.0);
+        assert_eq!(resolution.velocity.vx, 0.0);
+    }
+
     #[test]
     fn test_cell_type_conversion() {
         assert_eq!(CellType::from(0), CellType::Free);
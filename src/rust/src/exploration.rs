@@ -0,0 +1,341 @@
+//! Frontier-based exploration of an initially-unknown environment
+//!
+//! Unlike the rest of the crate, which assumes the `Environment` grid is
+//! fully known up front, this module lets a `Robot` build up its own belief
+//! grid by sensing nearby cells, then drives toward the nearest frontier
+//! between known and unknown space until nothing is left to discover.
+
+use crate::environment::{CellType, Environment};
+use crate::pathfinding::AStarPlanner;
+use crate::robot::Robot;
+use crate::types::Position;
+use ndarray::Array2;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Belief state of a single cell in the exploration grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CellState {
+    Unknown,
+    Free,
+    Occupied,
+}
+
+/// The robot's belief about the environment, built up purely from sensing
+#[derive(Debug, Clone)]
+pub struct BeliefGrid {
+    cells: Array2<CellState>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl BeliefGrid {
+    /// Create a grid of the given size with every cell `Unknown`
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            cells: Array2::from_elem((height, width), CellState::Unknown),
+            width,
+            height,
+        }
+    }
+
+    /// State of the cell at `(x, y)`, or `Unknown` if out of bounds
+    pub fn get(&self, x: usize, y: usize) -> CellState {
+        if x < self.width && y < self.height {
+            self.cells[[y, x]]
+        } else {
+            CellState::Unknown
+        }
+    }
+
+    fn set(&mut self, x: usize, y: usize, state: CellState) {
+        if x < self.width && y < self.height {
+            self.cells[[y, x]] = state;
+        }
+    }
+
+    /// A frontier cell is any `Free` cell adjacent to at least one `Unknown` cell
+    pub fn is_frontier(&self, x: usize, y: usize) -> bool {
+        if self.get(x, y) != CellState::Free {
+            return false;
+        }
+
+        for (dx, dy) in [(0i32, 1i32), (0, -1), (1, 0), (-1, 0)] {
+            let nx = x as i32 + dx;
+            let ny = y as i32 + dy;
+            if nx < 0 || ny < 0 {
+                continue;
+            }
+            if self.get(nx as usize, ny as usize) == CellState::Unknown {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// All frontier cells in the grid
+    pub fn frontiers(&self) -> Vec<(usize, usize)> {
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.is_frontier(x, y))
+            .collect()
+    }
+
+    /// Group frontier cells into contiguous clusters via 4-connected flood fill
+    pub fn cluster_frontiers(&self) -> Vec<Vec<(usize, usize)>> {
+        let frontier_set: std::collections::HashSet<(usize, usize)> =
+            self.frontiers().into_iter().collect();
+        let mut visited = std::collections::HashSet::new();
+        let mut clusters = Vec::new();
+
+        for &start in &frontier_set {
+            if visited.contains(&start) {
+                continue;
+            }
+
+            let mut cluster = Vec::new();
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+            visited.insert(start);
+
+            while let Some((cx, cy)) = queue.pop_front() {
+                cluster.push((cx, cy));
+
+                for (dx, dy) in [(0i32, 1i32), (0, -1), (1, 0), (-1, 0)] {
+                    let nx = cx as i32 + dx;
+                    let ny = cy as i32 + dy;
+                    if nx < 0 || ny < 0 {
+                        continue;
+                    }
+                    let neighbor = (nx as usize, ny as usize);
+                    if frontier_set.contains(&neighbor) && !visited.contains(&neighbor) {
+                        visited.insert(neighbor);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+
+            clusters.push(cluster);
+        }
+
+        clusters
+    }
+
+    /// Fraction of cells whose state is no longer `Unknown`
+    pub fn coverage_fraction(&self) -> f64 {
+        let total = (self.width * self.height) as f64;
+        if total == 0.0 {
+            return 1.0;
+        }
+
+        let known = self.cells.iter().filter(|&&c| c != CellState::Unknown).count();
+        known as f64 / total
+    }
+
+    /// Build a synthetic `Environment` from current beliefs so the existing
+    /// `AStarPlanner` can route over known-`Free` cells only; `Unknown` and
+    /// `Occupied` cells are both treated as obstacles.
+    pub fn as_environment(&self) -> Environment {
+        let mut grid = Array2::from_elem((self.height, self.width), CellType::Obstacle as u8);
+        for ((y, x), &state) in self.cells.indexed_iter() {
+            if state == CellState::Free {
+                grid[[y, x]] = CellType::Free as u8;
+            }
+        }
+        Environment::from_grid(grid)
+    }
+}
+
+/// Drives a `Robot` through an unknown `Environment`, sensing nearby cells
+/// and routing toward the nearest unexplored frontier each tick
+pub struct Explorer<'a> {
+    environment: &'a Environment,
+    pub robot: Robot,
+    pub grid: BeliefGrid,
+    path: Vec<(usize, usize)>,
+}
+
+impl<'a> Explorer<'a> {
+    /// Create an explorer that senses against `environment` (the ground
+    /// truth) but only plans over what it has discovered so far
+    pub fn new(environment: &'a Environment, robot: Robot) -> Self {
+        let grid = BeliefGrid::new(environment.width, environment.height);
+        Self { environment, robot, grid, path: Vec::new() }
+    }
+
+    /// Reveal all true cells within the robot's `sensor_range`, marking
+    /// sensed obstacles `Occupied` and sensed empties `Free`
+    fn sense(&mut self) {
+        let (rx, ry) = self.robot.position.to_grid();
+        let range = self.robot.sensor_range.ceil() as i32;
+
+        for dy in -range..=range {
+            for dx in -range..=range {
+                let x = rx as i32 + dx;
+                let y = ry as i32 + dy;
+                if x < 0 || y < 0 {
+                    continue;
+                }
+                let (x, y) = (x as usize, y as usize);
+                if x >= self.environment.width || y >= self.environment.height {
+                    continue;
+                }
+
+                let cell_center = Position::new(x as f64, y as f64);
+                if self.robot.position.distance_to(&cell_center) > self.robot.sensor_range {
+                    continue;
+                }
+
+                let state = if self.environment.is_valid_position(x, y) {
+                    CellState::Free
+                } else {
+                    CellState::Occupied
+                };
+                self.grid.set(x, y, state);
+            }
+        }
+    }
+
+    /// Nearest reachable frontier cluster's target cell, chosen by shortest
+    /// known-`Free` path cost rather than straight-line distance
+    fn nearest_reachable_frontier_target(&self) -> Option<(usize, usize)> {
+        let clusters = self.grid.cluster_frontiers();
+        if clusters.is_empty() {
+            return None;
+        }
+
+        let known_environment = self.grid.as_environment();
+        let planner = AStarPlanner::new(&known_environment);
+        let start = self.robot.position.to_grid();
+
+        clusters
+            .iter()
+            .filter_map(|cluster| {
+                let target = Self::closest_cell_to_centroid(cluster);
+                planner.find_path(start, target, false).map(|path| (target, path.len()))
+            })
+            .min_by_key(|&(_, cost)| cost)
+            .map(|(target, _)| target)
+    }
+
+    /// The cluster member closest to the cluster's own centroid, used as a
+    /// concrete routing target since the centroid itself may not be a cell
+    fn closest_cell_to_centroid(cluster: &[(usize, usize)]) -> (usize, usize) {
+        let count = cluster.len() as f64;
+        let (sum_x, sum_y) = cluster.iter().fold((0.0, 0.0), |(sx, sy), &(x, y)| {
+            (sx + x as f64, sy + y as f64)
+        });
+        let centroid = Position::new(sum_x / count, sum_y / count);
+
+        *cluster
+            .iter()
+            .min_by(|a, b| {
+                let da = Position::new(a.0 as f64, a.1 as f64).distance_to(&centroid);
+                let db = Position::new(b.0 as f64, b.1 as f64).distance_to(&centroid);
+                da.partial_cmp(&db).unwrap()
+            })
+            .expect("cluster is never empty")
+    }
+
+    /// Advance exploration by one step: sense, replan if needed, and move
+    /// one cell along the current route. Returns `None` once no frontier is
+    /// reachable over known-`Free` cells (in particular once none remain),
+    /// meaning exploration is complete.
+    pub fn step(&mut self) -> Option<Position> {
+        self.sense();
+
+        if self.path.is_empty() {
+            let target = self.nearest_reachable_frontier_target()?;
+            let known_environment = self.grid.as_environment();
+            let planner = AStarPlanner::new(&known_environment);
+            let start = self.robot.position.to_grid();
+
+            self.path = planner.find_path(start, target, false).unwrap_or_default();
+            if self.path.first() == Some(&start) {
+                self.path.remove(0);
+            }
+        }
+
+        if self.path.is_empty() {
+            return None;
+        }
+
+        let (nx, ny) = self.path.remove(0);
+        let dx = nx as f64 - self.robot.position.x;
+        let dy = ny as f64 - self.robot.position.y;
+        self.robot.move_by(dx, dy);
+
+        Some(self.robot.position)
+    }
+
+    /// Fraction of the environment that has been sensed so far
+    pub fn coverage_fraction(&self) -> f64 {
+        self.grid.coverage_fraction()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_grid_is_entirely_unknown() {
+        let grid = BeliefGrid::new(5, 5);
+        assert!(grid.frontiers().is_empty());
+        assert_eq!(grid.coverage_fraction(), 0.0);
+    }
+
+    #[test]
+    fn test_frontier_detection() {
+        let mut grid = BeliefGrid::new(5, 5);
+        grid.set(2, 2, CellState::Free);
+        assert!(grid.is_frontier(2, 2));
+
+        grid.set(1, 2, CellState::Free);
+        grid.set(3, 2, CellState::Free);
+        grid.set(2, 1, CellState::Free);
+        grid.set(2, 3, CellState::Free);
+        assert!(!grid.is_frontier(2, 2));
+    }
+
+    #[test]
+    fn test_cluster_frontiers_groups_adjacent_cells() {
+        let mut grid = BeliefGrid::new(10, 10);
+        grid.set(1, 1, CellState::Free);
+        grid.set(2, 1, CellState::Free);
+        grid.set(8, 8, CellState::Free);
+
+        let clusters = grid.cluster_frontiers();
+        assert_eq!(clusters.len(), 2);
+    }
+
+    #[test]
+    fn test_explorer_senses_cells_within_range() {
+        let environment = Environment::create_empty_room(20, 20);
+        let mut robot = Robot::new(Position::new(10.0, 10.0));
+        robot.sensor_range = 2.0;
+        let mut explorer = Explorer::new(&environment, robot);
+
+        explorer.step();
+
+        assert_eq!(explorer.grid.get(10, 10), CellState::Free);
+        // Far outside the sensor range, the corner is still unmapped
+        assert_eq!(explorer.grid.get(19, 19), CellState::Unknown);
+        assert!(explorer.coverage_fraction() > 0.0);
+        assert!(explorer.coverage_fraction() < 1.0);
+    }
+
+    #[test]
+    fn test_explorer_terminates_when_fully_explored() {
+        let environment = Environment::create_empty_room(6, 6);
+        let mut robot = Robot::new(Position::new(3.0, 3.0));
+        robot.sensor_range = 10.0;
+        let mut explorer = Explorer::new(&environment, robot);
+
+        // A sensor range covering the whole room should fully reveal it
+        // on the very first sense, leaving no frontiers to chase.
+        assert!(explorer.step().is_none());
+        assert_eq!(explorer.coverage_fraction(), 1.0);
+    }
+}
@@ -4,10 +4,16 @@
 //! cleaner simulator with advanced path planning, SLAM, and control algorithms.
 
 pub mod robot;
+pub mod assignment;
+pub mod coverage;
 pub mod environment;
+pub mod exploration;
+pub mod optimizer;
 pub mod pathfinding;
+pub mod pheromone;
 pub mod slam;
 pub mod simulator;
+pub mod tour;
 pub mod types;
 
 pub use robot::{Robot, RobotState, CleaningMode};
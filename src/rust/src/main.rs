@@ -1,10 +1,32 @@
 //! Robot Vacuum Cleaner CLI Application
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use env_logger::Env;
 use robot_vacuum_cleaner::{Robot, Environment, Simulator, Position};
+use robot_vacuum_cleaner::environment::{Boundary, BoundaryCondition};
+use robot_vacuum_cleaner::optimizer::{Evolver, EvolverConfig};
 use robot_vacuum_cleaner::simulator::{SimulationConfig, SimulationResults};
 
+/// CLI-selectable boundary policy, applied uniformly to all four edges
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum BoundaryArg {
+    Kill,
+    Reflect,
+    Periodic,
+    Absorb,
+}
+
+impl From<BoundaryArg> for BoundaryCondition {
+    fn from(value: BoundaryArg) -> Self {
+        match value {
+            BoundaryArg::Kill => BoundaryCondition::Kill,
+            BoundaryArg::Reflect => BoundaryCondition::Reflect,
+            BoundaryArg::Periodic => BoundaryCondition::Periodic,
+            BoundaryArg::Absorb => BoundaryCondition::Absorb,
+        }
+    }
+}
+
 /// Robot Vacuum Cleaner Simulator
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -36,6 +58,31 @@ struct Args {
     /// Verbose output
     #[arg(short, long)]
     verbose: bool,
+
+    /// Edge-of-room boundary policy
+    #[arg(short, long, value_enum, default_value_t = BoundaryArg::Reflect)]
+    boundary: BoundaryArg,
+
+    /// Evolve a cleaning route with the genetic optimizer instead of running
+    /// the default cleaning state machine
+    #[arg(long)]
+    evolve: bool,
+
+    /// Number of generations for the genetic optimizer
+    #[arg(long, default_value_t = 100)]
+    generations: usize,
+
+    /// Population size for the genetic optimizer
+    #[arg(long, default_value_t = 50)]
+    population_size: usize,
+
+    /// Per-gene mutation probability for the genetic optimizer
+    #[arg(long, default_value_t = 0.05)]
+    mutation_rate: f64,
+
+    /// Number of robots in the cleaning fleet
+    #[arg(long, default_value_t = 1)]
+    fleet_size: usize,
 }
 
 fn main() {
@@ -52,19 +99,45 @@ fn main() {
     let environment = Environment::create_empty_room(args.width, args.height);
     log::info!("Environment created: {}x{}", args.width, args.height);
 
-    // Create robot
-    let robot = Robot::new(Position::new(args.start_x, args.start_y));
-    log::info!("Robot initialized at ({}, {})", args.start_x, args.start_y);
+    // Create robot(s), spreading a multi-robot fleet out around the start position
+    let robots: Vec<Robot> = (0..args.fleet_size.max(1))
+        .map(|i| Robot::new(Position::new(args.start_x + i as f64, args.start_y)))
+        .collect();
+    log::info!("{} robot(s) initialized near ({}, {})", robots.len(), args.start_x, args.start_y);
+
+    if args.evolve {
+        let robot = Robot::new(Position::new(args.start_x, args.start_y));
+        let evolver_config = EvolverConfig {
+            generations: args.generations,
+            population_size: args.population_size,
+            mutation_rate: args.mutation_rate,
+            ..EvolverConfig::default()
+        };
+
+        log::info!("Evolving cleaning route ({} generations, population {})...",
+            evolver_config.generations, evolver_config.population_size);
+
+        let mut evolver = Evolver::new(evolver_config);
+        let (_, fitness) = evolver.run(&environment, robot.position);
+
+        println!("\n=== Evolved Route ===");
+        println!("Best fitness: {:.2}", fitness);
+        println!("======================\n");
+
+        std::process::exit(0);
+    }
 
     // Create simulation config
     let config = SimulationConfig {
         max_steps: args.max_steps,
         enable_slam: args.slam,
         tick_rate: 0.1,
+        boundary: Boundary::uniform(args.boundary.into()),
+        ..SimulationConfig::default()
     };
 
     // Create and run simulator
-    let mut simulator = Simulator::new(robot, environment, config);
+    let mut simulator = Simulator::new(robots, environment, config);
     let results = simulator.run();
 
     // Print results
@@ -72,6 +145,7 @@ fn main() {
     println!("Steps: {}", results.steps);
     println!("Success: {}", results.success);
     println!("Cleaning Coverage: {:.2}%", results.cleaning_coverage);
+    println!("Per-Robot Coverage: {:?}", results.per_robot_coverage);
     println!("Total Distance: {:.2}m", results.total_distance);
     println!("Battery Cycles: {}", results.battery_cycles);
     println!("==========================\n");
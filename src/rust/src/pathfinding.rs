@@ -28,16 +28,38 @@ impl PartialEq for AStarNode {
 
 impl Eq for AStarNode {}
 
+impl Ord for AStarNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f_cost().partial_cmp(&self.f_cost()).unwrap_or(Ordering::Equal)
+    }
+}
+
 impl PartialOrd for AStarNode {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.f_cost().partial_cmp(&self.f_cost())
+        Some(self.cmp(other))
     }
 }
 
-impl Ord for AStarNode {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
-    }
+/// Outcome of a bounded A* search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStatus {
+    /// The goal was reached; as with `Partial`, the last move may take `cost`
+    /// up to one step past the bound, since only expansion is bounded
+    Success,
+    /// The goal was unreachable within the cost bound; `path` leads to the
+    /// frontier node closest to the goal instead
+    Partial,
+    /// The start node itself is isolated, or the start or goal is not a valid
+    /// cell; no path exists
+    Fail,
+}
+
+/// Result of a bounded A* search
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathResult {
+    pub status: PathStatus,
+    pub path: Vec<(usize, usize)>,
+    pub cost: f64,
 }
 
 /// A* pathfinding algorithm
@@ -115,25 +137,109 @@ impl<'a> AStarPlanner<'a> {
 
         while let Some(current) = open_set.pop() {
             if current.position == goal {
-                // Reconstruct path
-                let mut path = Vec::new();
-                let mut pos = goal;
-
-                while let Some(node) = node_map.get(&pos) {
-                    path.push(pos);
-                    if let Some(parent) = node.parent {
-                        pos = parent;
-                    } else {
-                        break;
+                return Some(Self::reconstruct_path(&node_map, goal));
+            }
+
+            closed_set.insert(current.position);
+
+            for neighbor_pos in self.get_neighbors(current.position, diagonal) {
+                if closed_set.contains(&neighbor_pos) {
+                    continue;
+                }
+
+                let move_cost = if neighbor_pos.0 == current.position.0
+                    || neighbor_pos.1 == current.position.1
+                {
+                    1.0
+                } else {
+                    1.414 // sqrt(2) for diagonal
+                };
+
+                let g_cost = current.g_cost + move_cost;
+                let h_cost = self.heuristic(neighbor_pos, goal);
+
+                let neighbor_node = AStarNode {
+                    position: neighbor_pos,
+                    g_cost,
+                    h_cost,
+                    parent: Some(current.position),
+                };
+
+                if let Some(existing) = node_map.get(&neighbor_pos) {
+                    if g_cost < existing.g_cost {
+                        node_map.insert(neighbor_pos, neighbor_node.clone());
+                        open_set.push(neighbor_node);
                     }
+                } else {
+                    node_map.insert(neighbor_pos, neighbor_node.clone());
+                    open_set.push(neighbor_node);
                 }
+            }
+        }
+
+        None // No path found
+    }
 
-                path.reverse();
-                return Some(path);
+    /// Find a path from start to goal, bounded by `max_cost`. If the goal is
+    /// unreachable within the bound, falls back to the best-effort path to
+    /// whichever frontier node came closest to the goal, rather than giving
+    /// up entirely the way `find_path` does.
+    pub fn find_path_bounded(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        diagonal: bool,
+        max_cost: f64,
+    ) -> PathResult {
+        if !self.environment.is_valid_position(start.0, start.1)
+            || !self.environment.is_valid_position(goal.0, goal.1)
+        {
+            return PathResult { status: PathStatus::Fail, path: Vec::new(), cost: 0.0 };
+        }
+
+        let mut open_set = BinaryHeap::new();
+        let mut closed_set = HashSet::new();
+        let mut node_map: HashMap<(usize, usize), AStarNode> = HashMap::new();
+
+        let start_h = self.heuristic(start, goal);
+        let start_node = AStarNode {
+            position: start,
+            g_cost: 0.0,
+            h_cost: start_h,
+            parent: None,
+        };
+
+        open_set.push(start_node.clone());
+        node_map.insert(start, start_node);
+
+        let mut closest_position = start;
+        let mut closest_estimate = start_h;
+        let mut closest_cost = 0.0;
+
+        while let Some(current) = open_set.pop() {
+            // Updated on every pop, not just on neighbor generation, so the
+            // best-effort fallback always reflects the frontier node nearest
+            // the goal even if the search never expands further.
+            if current.h_cost < closest_estimate {
+                closest_estimate = current.h_cost;
+                closest_position = current.position;
+                closest_cost = current.g_cost;
+            }
+
+            if current.position == goal {
+                return PathResult {
+                    status: PathStatus::Success,
+                    path: Self::reconstruct_path(&node_map, goal),
+                    cost: current.g_cost,
+                };
             }
 
             closed_set.insert(current.position);
 
+            if current.g_cost > max_cost {
+                continue;
+            }
+
             for neighbor_pos in self.get_neighbors(current.position, diagonal) {
                 if closed_set.contains(&neighbor_pos) {
                     continue;
@@ -169,13 +275,154 @@ impl<'a> AStarPlanner<'a> {
             }
         }
 
-        None // No path found
+        if node_map.len() <= 1 {
+            return PathResult { status: PathStatus::Fail, path: Vec::new(), cost: 0.0 };
+        }
+
+        PathResult {
+            status: PathStatus::Partial,
+            path: Self::reconstruct_path(&node_map, closest_position),
+            cost: closest_cost,
+        }
+    }
+
+    /// Walk parent pointers from `goal` back to the root and return the path
+    /// in start-to-goal order
+    fn reconstruct_path(
+        node_map: &HashMap<(usize, usize), AStarNode>,
+        goal: (usize, usize),
+    ) -> Vec<(usize, usize)> {
+        let mut path = Vec::new();
+        let mut pos = goal;
+
+        while let Some(node) = node_map.get(&pos) {
+            path.push(pos);
+            if let Some(parent) = node.parent {
+                pos = parent;
+            } else {
+                break;
+            }
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Find a path from start to goal whose edge costs are scaled by
+    /// `cost_fn` (e.g. floor type, or a discount near known dirt) and whose
+    /// heuristic is scaled by `weight`, trading optimality for speed when
+    /// `weight > 1.0`. `find_path` is the `weight == 1.0`, unit-cost special
+    /// case of this search.
+    pub fn find_path_weighted(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        diagonal: bool,
+        weight: f64,
+        cost_fn: impl Fn((usize, usize)) -> f64,
+    ) -> Option<Vec<(usize, usize)>> {
+        if !self.environment.is_valid_position(start.0, start.1)
+            || !self.environment.is_valid_position(goal.0, goal.1)
+        {
+            return None;
+        }
+
+        let mut open_set = BinaryHeap::new();
+        let mut closed_set = HashSet::new();
+        let mut node_map: HashMap<(usize, usize), AStarNode> = HashMap::new();
+
+        let start_node = AStarNode {
+            position: start,
+            g_cost: 0.0,
+            h_cost: weight * self.heuristic(start, goal),
+            parent: None,
+        };
+
+        open_set.push(start_node.clone());
+        node_map.insert(start, start_node);
+
+        while let Some(current) = open_set.pop() {
+            if current.position == goal {
+                return Some(Self::reconstruct_path(&node_map, goal));
+            }
+
+            closed_set.insert(current.position);
+
+            for neighbor_pos in self.get_neighbors(current.position, diagonal) {
+                if closed_set.contains(&neighbor_pos) {
+                    continue;
+                }
+
+                let base_move_cost = if neighbor_pos.0 == current.position.0
+                    || neighbor_pos.1 == current.position.1
+                {
+                    1.0
+                } else {
+                    1.414 // sqrt(2) for diagonal
+                };
+
+                let move_cost = base_move_cost * cost_fn(neighbor_pos);
+                let g_cost = current.g_cost + move_cost;
+                let h_cost = weight * self.heuristic(neighbor_pos, goal);
+
+                let neighbor_node = AStarNode {
+                    position: neighbor_pos,
+                    g_cost,
+                    h_cost,
+                    parent: Some(current.position),
+                };
+
+                if let Some(existing) = node_map.get(&neighbor_pos) {
+                    if g_cost < existing.g_cost {
+                        node_map.insert(neighbor_pos, neighbor_node.clone());
+                        open_set.push(neighbor_node);
+                    }
+                } else {
+                    node_map.insert(neighbor_pos, neighbor_node.clone());
+                    open_set.push(neighbor_node);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Manhattan radius within which an attractor cell discounts traversal cost
+const ATTRACTOR_RADIUS: i32 = 3;
+
+/// Cost multiplier applied near an attractor cell, pulling the search
+/// mildly toward known dirt without overriding floor-type cost entirely
+const ATTRACTOR_DISCOUNT: f64 = 0.5;
+
+/// Build a `find_path_weighted` cost function that blends the environment's
+/// per-cell floor cost with a discount near any `attractors` (e.g. known
+/// dirty spots), mirroring a router that mixes distance-to-goal with
+/// distance-to-waypoints.
+pub fn attractor_cost_fn<'a>(
+    environment: &'a Environment,
+    attractors: &'a HashSet<(usize, usize)>,
+) -> impl Fn((usize, usize)) -> f64 + 'a {
+    move |pos| {
+        let base = environment.cell_cost(pos.0, pos.1);
+        let near_attractor = attractors.iter().any(|&attractor| {
+            let dx = (attractor.0 as i32 - pos.0 as i32).abs();
+            let dy = (attractor.1 as i32 - pos.1 as i32).abs();
+            dx + dy <= ATTRACTOR_RADIUS
+        });
+
+        if near_attractor {
+            base * ATTRACTOR_DISCOUNT
+        } else {
+            base
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::environment::CellType;
 
     #[test]
     fn test_astar_straight_line() {
@@ -214,4 +461,100 @@ mod tests {
         let dist = planner.heuristic((0, 0), (3, 4));
         assert_eq!(dist, 7.0); // Manhattan distance
     }
+
+    #[test]
+    fn test_find_path_bounded_succeeds_within_budget() {
+        let env = Environment::create_empty_room(30, 30);
+        let planner = AStarPlanner::new(&env);
+
+        let result = planner.find_path_bounded((5, 5), (10, 5), false, 100.0);
+
+        assert_eq!(result.status, PathStatus::Success);
+        assert_eq!(result.path[0], (5, 5));
+        assert_eq!(result.path[result.path.len() - 1], (10, 5));
+        assert_eq!(result.cost, 5.0);
+    }
+
+    #[test]
+    fn test_find_path_bounded_returns_partial_when_goal_out_of_budget() {
+        let env = Environment::create_empty_room(30, 30);
+        let planner = AStarPlanner::new(&env);
+
+        let result = planner.find_path_bounded((5, 5), (25, 5), false, 3.0);
+
+        assert_eq!(result.status, PathStatus::Partial);
+        assert_eq!(result.path[0], (5, 5));
+        // The fallback path should make progress toward the goal; expansion
+        // stops one step past the cost bound, since a node is only skipped
+        // from expanding (not dropped) once its own g_cost exceeds it.
+        assert!(result.cost >= 3.0 && result.cost <= 4.0);
+        let (last_x, _) = *result.path.last().unwrap();
+        assert!(last_x > 5);
+    }
+
+    #[test]
+    fn test_find_path_bounded_fails_when_start_is_isolated() {
+        let mut grid = ndarray::Array2::zeros((10, 10));
+        for x in 0..10 {
+            grid[[0, x]] = CellType::Obstacle as u8;
+            grid[[9, x]] = CellType::Obstacle as u8;
+        }
+        for y in 0..10 {
+            grid[[y, 0]] = CellType::Obstacle as u8;
+            grid[[y, 9]] = CellType::Obstacle as u8;
+        }
+        // Box the start cell in completely with a ring of obstacles
+        for (x, y) in [(4, 4), (5, 4), (6, 4), (4, 5), (6, 5), (4, 6), (5, 6), (6, 6)] {
+            grid[[y, x]] = CellType::Obstacle as u8;
+        }
+
+        let env = Environment::from_grid(grid);
+        let planner = AStarPlanner::new(&env);
+
+        let result = planner.find_path_bounded((5, 5), (1, 1), false, 100.0);
+
+        assert_eq!(result.status, PathStatus::Fail);
+        assert!(result.path.is_empty());
+    }
+
+    #[test]
+    fn test_find_path_weighted_matches_find_path_at_unit_weight_and_cost() {
+        let env = Environment::create_empty_room(30, 30);
+        let planner = AStarPlanner::new(&env);
+
+        let unweighted = planner.find_path((5, 5), (10, 5), false).unwrap();
+        let weighted = planner.find_path_weighted((5, 5), (10, 5), false, 1.0, |_| 1.0).unwrap();
+
+        assert_eq!(unweighted, weighted);
+    }
+
+    #[test]
+    fn test_find_path_weighted_routes_around_expensive_floor() {
+        let mut env = Environment::create_empty_room(10, 5);
+        // Expensive carpet across the middle row, except for a cheap gap
+        // near the left wall where the detour can cross instead
+        for x in 2..9 {
+            env.set_floor_cost(x, 2, 10.0);
+        }
+
+        let planner = AStarPlanner::new(&env);
+        let path = planner
+            .find_path_weighted((1, 1), (8, 3), false, 1.0, |pos| env.cell_cost(pos.0, pos.1))
+            .unwrap();
+
+        // The detour should cross row 2 through the cheap gap near the left
+        // wall rather than cutting straight through the expensive carpet
+        let crossing = path.iter().find(|&&(_, y)| y == 2).expect("path must cross row 2");
+        assert!(crossing.0 < 3);
+    }
+
+    #[test]
+    fn test_attractor_cost_fn_discounts_cells_near_dirt() {
+        let env = Environment::create_empty_room(10, 10);
+        let attractors: HashSet<(usize, usize)> = [(5, 5)].into_iter().collect();
+        let cost_fn = attractor_cost_fn(&env, &attractors);
+
+        assert_eq!(cost_fn((5, 5)), ATTRACTOR_DISCOUNT);
+        assert_eq!(cost_fn((1, 1)), 1.0);
+    }
 }
@@ -0,0 +1,222 @@
+//! Pheromone-guided complete-coverage planner
+//!
+//! Backs [`crate::robot::CleaningMode::Pheromone`]: instead of a precomputed
+//! boustrophedon decomposition, the robot deposits a decaying scalar trail
+//! on cells it occupies and steers toward whichever neighbor carries the
+//! least pheromone, which naturally spreads coverage outward from already-
+//! visited regions the way real ant-colony foraging avoids revisiting food
+//! sources it has already stripped.
+
+use crate::environment::Environment;
+use crate::pathfinding::AStarPlanner;
+use crate::robot::Robot;
+use ndarray::Array2;
+use std::collections::{HashSet, VecDeque};
+
+/// Amount of pheromone deposited on the robot's current cell each tick
+const DEPOSIT_AMOUNT: f64 = 1.0;
+
+/// Multiplicative decay applied to every cell's pheromone each tick
+const DECAY_RATE: f64 = 0.98;
+
+/// A cell is considered part of the robot's own trail, and thus worth
+/// escaping via a longer A* hop, once every neighbor exceeds this level
+const BOXED_IN_THRESHOLD: f64 = DEPOSIT_AMOUNT * 0.5;
+
+/// Grid of decaying pheromone values left behind by the robot
+#[derive(Debug, Clone)]
+pub struct PheromoneField {
+    grid: Array2<f64>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl PheromoneField {
+    /// Create a field of the given size with no pheromone anywhere
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { grid: Array2::zeros((height, width)), width, height }
+    }
+
+    /// Pheromone level at `(x, y)`, or `0.0` if out of bounds
+    pub fn at(&self, x: usize, y: usize) -> f64 {
+        if x < self.width && y < self.height {
+            self.grid[[y, x]]
+        } else {
+            0.0
+        }
+    }
+
+    /// Deposit a fixed amount of pheromone at `(x, y)`
+    pub fn deposit(&mut self, x: usize, y: usize) {
+        if x < self.width && y < self.height {
+            self.grid[[y, x]] += DEPOSIT_AMOUNT;
+        }
+    }
+
+    /// Decay every cell's pheromone multiplicatively
+    pub fn decay(&mut self) {
+        self.grid.mapv_inplace(|v| v * DECAY_RATE);
+    }
+}
+
+/// Plans the next cell to visit for a robot in [`CleaningMode::Pheromone`](crate::robot::CleaningMode::Pheromone)
+pub struct PheromonePlanner<'a> {
+    environment: &'a Environment,
+    pub field: PheromoneField,
+    returning: bool,
+}
+
+impl<'a> PheromonePlanner<'a> {
+    /// Create a planner over `environment`, with an empty pheromone field
+    pub fn new(environment: &'a Environment) -> Self {
+        Self {
+            environment,
+            field: PheromoneField::new(environment.width, environment.height),
+            returning: false,
+        }
+    }
+
+    /// Decay the field, deposit at the robot's current cell, and choose the
+    /// next cell to move to. Once `robot.should_return_to_dock()` fires,
+    /// deposits are suspended and every subsequent call routes back to the
+    /// dock instead, mirroring the ant model's foraging/return behavior.
+    pub fn step(&mut self, robot: &Robot) -> Option<(usize, usize)> {
+        self.field.decay();
+
+        let current = robot.position.to_grid();
+
+        if robot.should_return_to_dock() {
+            self.returning = true;
+        }
+
+        if self.returning {
+            return self.route_to_dock(robot, current);
+        }
+
+        self.field.deposit(current.0, current.1);
+        self.choose_next_cell(current)
+    }
+
+    fn route_to_dock(&self, robot: &Robot, current: (usize, usize)) -> Option<(usize, usize)> {
+        let dock = robot.dock_position?.to_grid();
+        let planner = AStarPlanner::new(self.environment);
+        let path = planner.find_path(current, dock, false)?;
+        path.into_iter().nth(1)
+    }
+
+    fn choose_next_cell(&self, current: (usize, usize)) -> Option<(usize, usize)> {
+        let neighbors = self.free_neighbors(current.0, current.1);
+        if neighbors.is_empty() {
+            return None;
+        }
+
+        let min_pheromone = neighbors
+            .iter()
+            .map(|&(x, y)| self.field.at(x, y))
+            .fold(f64::INFINITY, f64::min);
+
+        // Every neighbor is itself heavily trailed: take a short A* hop to
+        // the nearest lightly-pheromoned cell instead of pacing in place.
+        if min_pheromone > BOXED_IN_THRESHOLD {
+            if let Some(target) = self.nearest_low_pheromone_cell(current) {
+                let planner = AStarPlanner::new(self.environment);
+                if let Some(path) = planner.find_path(current, target, false) {
+                    if let Some(&next) = path.get(1) {
+                        return Some(next);
+                    }
+                }
+            }
+        }
+
+        neighbors
+            .into_iter()
+            .min_by(|&(ax, ay), &(bx, by)| {
+                self.field.at(ax, ay).partial_cmp(&self.field.at(bx, by)).unwrap()
+            })
+    }
+
+    fn free_neighbors(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        [(0i32, 1i32), (0, -1), (1, 0), (-1, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+                if nx < 0 || ny < 0 {
+                    return None;
+                }
+                let (nx, ny) = (nx as usize, ny as usize);
+                self.environment.is_valid_position(nx, ny).then_some((nx, ny))
+            })
+            .collect()
+    }
+
+    /// Breadth-first search outward from `start` for the nearest cell whose
+    /// pheromone has mostly decayed away, used to escape a self-laid trail
+    fn nearest_low_pheromone_cell(&self, start: (usize, usize)) -> Option<(usize, usize)> {
+        let mut visited = HashSet::new();
+        visited.insert(start);
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+
+        while let Some((x, y)) = queue.pop_front() {
+            if (x, y) != start && self.field.at(x, y) <= BOXED_IN_THRESHOLD {
+                return Some((x, y));
+            }
+
+            for (nx, ny) in self.free_neighbors(x, y) {
+                if visited.insert((nx, ny)) {
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Position;
+
+    #[test]
+    fn test_deposit_and_decay() {
+        let mut field = PheromoneField::new(10, 10);
+        field.deposit(5, 5);
+        assert_eq!(field.at(5, 5), DEPOSIT_AMOUNT);
+
+        field.decay();
+        assert!((field.at(5, 5) - DEPOSIT_AMOUNT * DECAY_RATE).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_planner_prefers_unvisited_neighbor() {
+        let environment = Environment::create_empty_room(10, 10);
+        let robot = Robot::new(Position::new(5.0, 5.0));
+        let mut planner = PheromonePlanner::new(&environment);
+
+        planner.field.deposit(6, 5);
+        planner.field.deposit(4, 5);
+        planner.field.deposit(5, 6);
+
+        let next = planner.step(&robot);
+        assert_eq!(next, Some((5, 4)));
+    }
+
+    #[test]
+    fn test_planner_routes_to_dock_when_battery_low() {
+        let environment = Environment::create_empty_room(10, 10);
+        let mut robot = Robot::new(Position::new(5.0, 5.0));
+        robot.set_dock_position(Position::new(2.0, 2.0));
+        robot.battery_level = 10.0;
+
+        let mut planner = PheromonePlanner::new(&environment);
+        let next = planner.step(&robot);
+
+        assert!(next.is_some());
+        let (nx, ny) = next.unwrap();
+        // Should move strictly closer to the dock rather than exploring
+        assert!(nx <= 5 && ny <= 5);
+        assert!(nx < 5 || ny < 5);
+    }
+}
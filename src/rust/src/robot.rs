@@ -1,6 +1,6 @@
 //! Robot vacuum core implementation
 
-use crate::types::{Position, SensorData, RobotStats};
+use crate::types::{Position, SensorData, RobotStats, Velocity};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -25,17 +25,23 @@ pub enum CleaningMode {
     Zigzag,
     WallFollow,
     Random,
+    /// Steers away from recently-visited cells using a decaying pheromone
+    /// trail, achieving full coverage without a precomputed decomposition
+    Pheromone,
 }
 
 /// Robot vacuum cleaner
 #[derive(Debug, Clone)]
 pub struct Robot {
     pub position: Position,
+    pub velocity: Velocity,
     pub battery_capacity: f64,
     pub battery_level: f64,
     pub cleaning_width: f64,
     pub speed: f64,
     pub sensor_range: f64,
+    /// Amount of dirt density subtracted from a cell per cleaning pass, in `[0, 1]`
+    pub suction_efficiency: f64,
     pub state: RobotState,
     pub mode: CleaningMode,
     pub heading: f64,
@@ -55,11 +61,13 @@ impl Robot {
 
         Self {
             position,
+            velocity: Velocity::new(0.0, 0.0),
             battery_capacity: 100.0,
             battery_level: 100.0,
             cleaning_width: 0.3,
             speed: 0.2,
             sensor_range: 2.0,
+            suction_efficiency: 0.3,
             state: RobotState::Idle,
             mode: CleaningMode::Auto,
             heading: 0.0,
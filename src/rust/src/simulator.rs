@@ -1,14 +1,48 @@
 //! Simulation controller
 
 use crate::robot::{Robot, RobotState};
-use crate::environment::Environment;
+use crate::environment::{Boundary, CellType, Environment};
 use crate::pathfinding::AStarPlanner;
+use crate::types::{Position, Velocity};
+
+/// Weights for the boids-style flocking controller that steers a fleet of
+/// robots so they cooperate instead of clustering or colliding.
+#[derive(Debug, Clone, Copy)]
+pub struct FlockingConfig {
+    /// How strongly robots push away from neighbors (and obstacles/cliffs)
+    pub separation_weight: f64,
+    /// How strongly robots pull toward the fleet centroid
+    pub cohesion_weight: f64,
+    /// How strongly robots align their heading with neighbors
+    pub alignment_weight: f64,
+    /// How strongly robots steer toward the nearest dirty cell
+    pub coverage_weight: f64,
+    /// Neighbors (and hazard cells) beyond this distance are ignored
+    pub neighbor_radius: f64,
+    /// Clamp on the resulting steering velocity's magnitude
+    pub max_speed: f64,
+}
+
+impl Default for FlockingConfig {
+    fn default() -> Self {
+        Self {
+            separation_weight: 1.5,
+            cohesion_weight: 0.3,
+            alignment_weight: 0.5,
+            coverage_weight: 1.0,
+            neighbor_radius: 5.0,
+            max_speed: 1.0,
+        }
+    }
+}
 
 /// Simulation configuration
 pub struct SimulationConfig {
     pub max_steps: usize,
     pub enable_slam: bool,
     pub tick_rate: f64,
+    pub boundary: Boundary,
+    pub flocking: FlockingConfig,
 }
 
 impl Default for SimulationConfig {
@@ -17,51 +51,66 @@ impl Default for SimulationConfig {
             max_steps: 10000,
             enable_slam: false,
             tick_rate: 0.1,
+            boundary: Boundary::default(),
+            flocking: FlockingConfig::default(),
         }
     }
 }
 
-/// Main simulator
+/// Main simulator, driving a fleet of one or more robots cooperatively
+/// cleaning the same environment
 pub struct Simulator {
-    pub robot: Robot,
+    pub robots: Vec<Robot>,
     pub environment: Environment,
     pub config: SimulationConfig,
     pub steps: usize,
 }
 
 impl Simulator {
-    /// Create new simulator
-    pub fn new(robot: Robot, environment: Environment, config: SimulationConfig) -> Self {
+    /// Create a new simulator over a fleet of robots
+    pub fn new(robots: Vec<Robot>, mut environment: Environment, config: SimulationConfig) -> Self {
+        environment.boundary = config.boundary;
         Self {
-            robot,
+            robots,
             environment,
             config,
             steps: 0,
         }
     }
 
+    /// Convenience constructor for the common single-robot case
+    pub fn single(robot: Robot, environment: Environment, config: SimulationConfig) -> Self {
+        Self::new(vec![robot], environment, config)
+    }
+
     /// Execute one simulation step
     pub fn step(&mut self) -> bool {
         self.steps += 1;
         self.environment.step(self.config.tick_rate);
 
-        // Simple simulation logic
-        match self.robot.state {
-            RobotState::Idle => {
-                self.robot.state = RobotState::Cleaning;
-            }
-            RobotState::Cleaning => {
-                // Check if should return to dock
-                if self.robot.should_return_to_dock() {
-                    self.robot.state = RobotState::ReturningToDock;
+        if self.robots.len() > 1 {
+            self.update_flocking_velocities();
+        }
+
+        for i in 0..self.robots.len() {
+            match self.robots[i].state {
+                RobotState::Idle => {
+                    self.robots[i].state = RobotState::Cleaning;
                 }
-            }
-            RobotState::Charging => {
-                if self.robot.charge(10.0) {
-                    self.robot.state = RobotState.Cleaning;
+                RobotState::Cleaning => {
+                    self.advance_position(i);
+
+                    if self.robots[i].should_return_to_dock() {
+                        self.robots[i].state = RobotState::ReturningToDock;
+                    }
                 }
+                RobotState::Charging => {
+                    if self.robots[i].charge(10.0) {
+                        self.robots[i].state = RobotState::Cleaning;
+                    }
+                }
+                _ => {}
             }
-            _ => {}
         }
 
         // Check max steps
@@ -72,9 +121,198 @@ impl Simulator {
         true
     }
 
+    /// Integrate robot `i`'s velocity for one tick and resolve the result
+    /// against the environment's boundary conditions.
+    fn advance_position(&mut self, i: usize) {
+        let dt = self.config.tick_rate;
+        let robot = &self.robots[i];
+        let tentative = Position::new(
+            robot.position.x + robot.velocity.vx * dt,
+            robot.position.y + robot.velocity.vy * dt,
+        );
+
+        let resolution = self.environment.resolve_boundary(tentative, robot.velocity);
+
+        if resolution.killed {
+            self.robots[i].state = RobotState::Error;
+            self.robots[i].stats.errors_encountered += 1;
+            return;
+        }
+
+        let dx = resolution.position.x - robot.position.x;
+        let dy = resolution.position.y - robot.position.y;
+        self.robots[i].move_by(dx, dy);
+        self.robots[i].velocity = resolution.velocity;
+
+        let (gx, gy) = self.robots[i].position.to_grid();
+        if self.environment.is_valid_position(gx, gy) {
+            self.environment.clean_cell(gx, gy, self.robots[i].suction_efficiency);
+        }
+    }
+
+    /// Recompute every robot's velocity for this tick from a blend of
+    /// separation, cohesion, alignment, obstacle repulsion, and a
+    /// coverage-seeking pull toward the nearest dirty cell.
+    fn update_flocking_velocities(&mut self) {
+        let weights = self.config.flocking;
+        let next_velocities: Vec<Velocity> = (0..self.robots.len())
+            .map(|i| self.steering_velocity(i, &weights))
+            .collect();
+
+        for (robot, velocity) in self.robots.iter_mut().zip(next_velocities) {
+            robot.velocity = velocity;
+        }
+    }
+
+    /// Blended steering vector for a single robot, clamped to `max_speed`
+    fn steering_velocity(&self, i: usize, weights: &FlockingConfig) -> Velocity {
+        let position = self.robots[i].position;
+        let radius = weights.neighbor_radius;
+
+        let mut separation = (0.0, 0.0);
+        let mut centroid = (0.0, 0.0);
+        let mut heading_sum = (0.0, 0.0);
+        let mut neighbor_count = 0.0;
+
+        for (j, other) in self.robots.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+
+            let dx = position.x - other.position.x;
+            let dy = position.y - other.position.y;
+            let distance = (dx * dx + dy * dy).sqrt();
+
+            if distance > 0.0 && distance < radius {
+                separation.0 += dx / (distance * distance);
+                separation.1 += dy / (distance * distance);
+                centroid.0 += other.position.x;
+                centroid.1 += other.position.y;
+                heading_sum.0 += other.velocity.vx;
+                heading_sum.1 += other.velocity.vy;
+                neighbor_count += 1.0;
+            }
+        }
+
+        // Repel from nearby obstacle/cliff cells using the same separation term
+        for (dx, dy) in self.nearby_hazard_offsets(position, radius) {
+            let distance = (dx * dx + dy * dy).sqrt();
+            if distance > 0.0 {
+                separation.0 += dx / (distance * distance);
+                separation.1 += dy / (distance * distance);
+            }
+        }
+
+        let cohesion = if neighbor_count > 0.0 {
+            (
+                centroid.0 / neighbor_count - position.x,
+                centroid.1 / neighbor_count - position.y,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+        let alignment = if neighbor_count > 0.0 {
+            (heading_sum.0 / neighbor_count, heading_sum.1 / neighbor_count)
+        } else {
+            (0.0, 0.0)
+        };
+
+        let coverage = self
+            .nearest_dirty_offset(position, radius)
+            .unwrap_or((0.0, 0.0));
+
+        let vx = separation.0 * weights.separation_weight
+            + cohesion.0 * weights.cohesion_weight
+            + alignment.0 * weights.alignment_weight
+            + coverage.0 * weights.coverage_weight;
+        let vy = separation.1 * weights.separation_weight
+            + cohesion.1 * weights.cohesion_weight
+            + alignment.1 * weights.alignment_weight
+            + coverage.1 * weights.coverage_weight;
+
+        let steering = Velocity::new(vx, vy);
+        let magnitude = steering.magnitude();
+        if magnitude > weights.max_speed && magnitude > 0.0 {
+            let scale = weights.max_speed / magnitude;
+            Velocity::new(vx * scale, vy * scale)
+        } else {
+            steering
+        }
+    }
+
+    /// Relative offsets (dx, dy) from `position` to obstacle/cliff cells
+    /// within `radius`
+    fn nearby_hazard_offsets(&self, position: Position, radius: f64) -> Vec<(f64, f64)> {
+        let cells = radius.ceil() as i32;
+        let (gx, gy) = position.to_grid();
+        let mut offsets = Vec::new();
+
+        for dy in -cells..=cells {
+            for dx in -cells..=cells {
+                let x = gx as i32 + dx;
+                let y = gy as i32 + dy;
+                if x < 0 || y < 0 {
+                    continue;
+                }
+                let (x, y) = (x as usize, y as usize);
+                if x >= self.environment.width || y >= self.environment.height {
+                    continue;
+                }
+
+                let cell = CellType::from(self.environment.grid[[y, x]]);
+                if matches!(cell, CellType::Obstacle | CellType::Cliff) {
+                    let world_dx = position.x - x as f64;
+                    let world_dy = position.y - y as f64;
+                    if (world_dx * world_dx + world_dy * world_dy).sqrt() < radius {
+                        offsets.push((world_dx, world_dy));
+                    }
+                }
+            }
+        }
+
+        offsets
+    }
+
+    /// Offset (dx, dy) from `position` to the nearest dirty free cell within
+    /// `radius`, if any
+    fn nearest_dirty_offset(&self, position: Position, radius: f64) -> Option<(f64, f64)> {
+        let cells = radius.ceil() as i32;
+        let (gx, gy) = position.to_grid();
+        let mut nearest: Option<(f64, (f64, f64))> = None;
+
+        for dy in -cells..=cells {
+            for dx in -cells..=cells {
+                let x = gx as i32 + dx;
+                let y = gy as i32 + dy;
+                if x < 0 || y < 0 {
+                    continue;
+                }
+                let (x, y) = (x as usize, y as usize);
+                if x >= self.environment.width || y >= self.environment.height {
+                    continue;
+                }
+
+                if !self.environment.is_dirty(x, y) || !self.environment.is_valid_position(x, y) {
+                    continue;
+                }
+
+                let world_dx = x as f64 - position.x;
+                let world_dy = y as f64 - position.y;
+                let distance = (world_dx * world_dx + world_dy * world_dy).sqrt();
+
+                if distance < radius && nearest.is_none_or(|(best, _)| distance < best) {
+                    nearest = Some((distance, (world_dx, world_dy)));
+                }
+            }
+        }
+
+        nearest.map(|(_, offset)| offset)
+    }
+
     /// Run complete simulation
     pub fn run(&mut self) -> SimulationResults {
-        log::info!("Starting simulation");
+        log::info!("Starting simulation with {} robot(s)", self.robots.len());
 
         while self.step() {
             // Simulation loop
@@ -85,9 +323,10 @@ impl Simulator {
         SimulationResults {
             steps: self.steps,
             cleaning_coverage: self.environment.get_cleaning_percentage(),
-            total_distance: self.robot.stats.total_distance,
-            battery_cycles: self.robot.stats.battery_cycles,
-            success: self.robot.state != RobotState::Error,
+            per_robot_coverage: self.robots.iter().map(|r| r.stats.area_cleaned).collect(),
+            total_distance: self.robots.iter().map(|r| r.stats.total_distance).sum(),
+            battery_cycles: self.robots.iter().map(|r| r.stats.battery_cycles).sum(),
+            success: self.robots.iter().all(|r| r.state != RobotState::Error),
         }
     }
 }
@@ -97,6 +336,8 @@ impl Simulator {
 pub struct SimulationResults {
     pub steps: usize,
     pub cleaning_coverage: f64,
+    /// Number of distinct cells each robot individually cleaned
+    pub per_robot_coverage: Vec<usize>,
     pub total_distance: f64,
     pub battery_cycles: usize,
     pub success: bool,
@@ -105,7 +346,8 @@ pub struct SimulationResults {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::Position;
+    use crate::environment::BoundaryCondition;
+    use crate::types::{Position, Velocity};
 
     #[test]
     fn test_simulator_creation() {
@@ -113,9 +355,10 @@ mod tests {
         let env = Environment::create_empty_room(30, 30);
         let config = SimulationConfig::default();
 
-        let sim = Simulator::new(robot, env, config);
+        let sim = Simulator::single(robot, env, config);
 
         assert_eq!(sim.steps, 0);
+        assert_eq!(sim.robots.len(), 1);
     }
 
     #[test]
@@ -124,11 +367,63 @@ mod tests {
         let env = Environment::create_empty_room(30, 30);
         let config = SimulationConfig::default();
 
-        let mut sim = Simulator::new(robot, env, config);
+        let mut sim = Simulator::single(robot, env, config);
 
         let should_continue = sim.step();
 
         assert!(should_continue);
         assert_eq!(sim.steps, 1);
     }
+
+    #[test]
+    fn test_boundary_kill_errors_robot() {
+        let mut robot = Robot::new(Position::new(0.4, 15.0));
+        robot.velocity = Velocity::new(-5.0, 0.0);
+        robot.state = RobotState::Cleaning;
+
+        let env = Environment::new(30, 30);
+        let config = SimulationConfig {
+            boundary: Boundary::uniform(BoundaryCondition::Kill),
+            ..SimulationConfig::default()
+        };
+
+        let mut sim = Simulator::single(robot, env, config);
+        sim.step();
+
+        assert_eq!(sim.robots[0].state, RobotState::Error);
+    }
+
+    #[test]
+    fn test_fleet_separation_pushes_robots_apart() {
+        let mut robot_a = Robot::new(Position::new(14.0, 15.0));
+        robot_a.state = RobotState::Cleaning;
+        let mut robot_b = Robot::new(Position::new(16.0, 15.0));
+        robot_b.state = RobotState::Cleaning;
+
+        let env = Environment::create_empty_room(30, 30);
+        let config = SimulationConfig::default();
+
+        let mut sim = Simulator::new(vec![robot_a, robot_b], env, config);
+        sim.update_flocking_velocities();
+
+        // Robot A (left) should be pushed further left, robot B (right) further right
+        assert!(sim.robots[0].velocity.vx < 0.0);
+        assert!(sim.robots[1].velocity.vx > 0.0);
+    }
+
+    #[test]
+    fn test_fleet_run_reports_per_robot_coverage() {
+        let robot_a = Robot::new(Position::new(10.0, 10.0));
+        let robot_b = Robot::new(Position::new(20.0, 20.0));
+        let env = Environment::create_empty_room(30, 30);
+        let config = SimulationConfig {
+            max_steps: 5,
+            ..SimulationConfig::default()
+        };
+
+        let mut sim = Simulator::new(vec![robot_a, robot_b], env, config);
+        let results = sim.run();
+
+        assert_eq!(results.per_robot_coverage.len(), 2);
+    }
 }
@@ -1,13 +1,72 @@
 //! SLAM (Simultaneous Localization and Mapping) implementation
-//!
-//! Placeholder for future SLAM implementation
 
-use crate::types::Pose;
+use crate::types::{Pose, SensorData};
 use ndarray::Array2;
+use rand::Rng;
+use std::f64::consts::{FRAC_PI_2, PI};
 
-/// Occupancy grid map
+/// Standard deviation (in grid cells) used to score predicted-vs-measured beam
+/// distances when reweighting particles.
+const SENSOR_SIGMA: f64 = 1.0;
+
+/// Treat any cell with occupancy probability above this as a ray-casting hit
+const OCCUPIED_THRESHOLD: f32 = 0.5;
+
+/// Step size (in cells) used while marching a beam through the grid
+const RAY_STEP: f64 = 0.5;
+
+/// Log-odds decrement applied to cells a beam passes through before its hit
+const L_FREE: f32 = 0.4;
+
+/// Log-odds increment applied to a beam's measured endpoint
+const L_OCC: f32 = 0.85;
+
+/// Log-odds clamp range, keeping cells from saturating to +/- infinity
+const L_MIN: f32 = -4.0;
+const L_MAX: f32 = 4.0;
+
+/// A pair of grids where writers mutate the "back" buffer and flip it into
+/// "front" atomically via `switch()`, so readers always see a coherent,
+/// tear-free snapshot even while the next scan is being integrated.
+#[derive(Debug, Clone)]
+pub(crate) struct DoubleBuffer<T> {
+    buffers: [Array2<T>; 2],
+    front_index: usize,
+}
+
+impl<T: Clone> DoubleBuffer<T> {
+    pub(crate) fn new(dim: (usize, usize), fill: T) -> Self {
+        Self {
+            buffers: [Array2::from_elem(dim, fill.clone()), Array2::from_elem(dim, fill)],
+            front_index: 0,
+        }
+    }
+
+    pub(crate) fn front(&self) -> &Array2<T> {
+        &self.buffers[self.front_index]
+    }
+
+    pub(crate) fn back_mut(&mut self) -> &mut Array2<T> {
+        &mut self.buffers[1 - self.front_index]
+    }
+
+    /// Mutate the published "front" buffer directly, for single-cell writes
+    /// that don't need the back-buffer/switch dance (no partial-write window
+    /// to hide, since there's only one writer and no intermediate state).
+    pub(crate) fn front_mut(&mut self) -> &mut Array2<T> {
+        &mut self.buffers[self.front_index]
+    }
+
+    pub(crate) fn switch(&mut self) {
+        self.front_index = 1 - self.front_index;
+    }
+}
+
+/// Occupancy grid map, stored internally as log-odds for numerically stable
+/// incremental updates and double-buffered so a mapping thread can read a
+/// consistent snapshot while the next scan is integrated.
 pub struct OccupancyGrid {
-    pub grid: Array2<f32>,
+    buffer: DoubleBuffer<f32>,
     pub width: usize,
     pub height: usize,
     pub resolution: f64,
@@ -17,21 +76,125 @@ impl OccupancyGrid {
     /// Create new occupancy grid
     pub fn new(width: usize, height: usize, resolution: f64) -> Self {
         Self {
-            grid: Array2::zeros((height, width)),
+            buffer: DoubleBuffer::new((height, width), 0.0),
             width,
             height,
             resolution,
         }
     }
 
-    /// Get probability at position
+    /// Current, stable-to-read grid
+    pub fn front(&self) -> &Array2<f32> {
+        self.buffer.front()
+    }
+
+    /// Grid being written by the next scan integration
+    pub fn back_mut(&mut self) -> &mut Array2<f32> {
+        self.buffer.back_mut()
+    }
+
+    /// Get probability at position, converted from the internal log-odds value
     pub fn get_probability(&self, x: usize, y: usize) -> f32 {
         if x < self.width && y < self.height {
-            self.grid[[y, x]]
+            let log_odds = self.buffer.front()[[y, x]];
+            1.0 - 1.0 / (1.0 + log_odds.exp())
         } else {
             0.5 // Unknown
         }
     }
+
+    /// Integrate one sensor scan using an inverse sensor model: for each of the
+    /// four beams, march cells along the beam direction, decrementing
+    /// log-odds by `L_FREE` for cells before the hit and adding `L_OCC` at the
+    /// measured endpoint, clamped to `[L_MIN, L_MAX]`. Writes land in the back
+    /// buffer; `switch()` publishes them atomically once the whole scan is in.
+    pub fn integrate_scan(&mut self, pose: &Pose, sensor: &SensorData) {
+        *self.buffer.back_mut() = self.buffer.front().clone();
+
+        let beams = [
+            (0.0, sensor.distance_front),
+            (FRAC_PI_2, sensor.distance_left),
+            (-FRAC_PI_2, sensor.distance_right),
+            (PI, sensor.distance_back),
+        ];
+
+        for (offset, measured) in beams {
+            self.integrate_beam(pose, offset, measured);
+        }
+
+        self.buffer.switch();
+    }
+
+    fn integrate_beam(&mut self, pose: &Pose, angle_offset: f64, measured: f64) {
+        let angle = pose.theta + angle_offset;
+        let (dx, dy) = (angle.cos(), angle.sin());
+        let hit = measured.is_finite();
+        let max_range = if hit { measured } else { self.width.max(self.height) as f64 };
+
+        let end = if hit { self.cell_at(pose, dx, dy, max_range) } else { None };
+        let mut last = None;
+        let mut travelled = 0.0;
+        while travelled < max_range {
+            if let Some((gx, gy)) = self.cell_at(pose, dx, dy, travelled) {
+                // Several samples can land in one cell: decrement each cell once,
+                // and never the endpoint cell
+                if last != Some((gx, gy)) && end != Some((gx, gy)) {
+                    let back = self.buffer.back_mut();
+                    back[[gy, gx]] = (back[[gy, gx]] - L_FREE).clamp(L_MIN, L_MAX);
+                }
+                last = Some((gx, gy));
+            } else {
+                break;
+            }
+            travelled += RAY_STEP;
+        }
+
+        if let Some((gx, gy)) = end {
+            let back = self.buffer.back_mut();
+            back[[gy, gx]] = (back[[gy, gx]] + L_OCC).clamp(L_MIN, L_MAX);
+        }
+    }
+
+    /// Grid cell at `travelled` cells along (dx, dy) from `pose`, if in bounds
+    fn cell_at(&self, pose: &Pose, dx: f64, dy: f64, travelled: f64) -> Option<(usize, usize)> {
+        let x = pose.x + dx * travelled;
+        let y = pose.y + dy * travelled;
+
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        let (gx, gy) = (x as usize, y as usize);
+        if gx >= self.width || gy >= self.height {
+            return None;
+        }
+
+        Some((gx, gy))
+    }
+
+    /// Cast a ray from `pose`, offset by `angle_offset` from its heading, and
+    /// return the distance (in grid cells) to the nearest occupied cell, or
+    /// `max_range` if nothing is hit first.
+    fn cast_ray(&self, pose: &Pose, angle_offset: f64, max_range: f64) -> f64 {
+        let angle = pose.theta + angle_offset;
+        let (dx, dy) = (angle.cos(), angle.sin());
+
+        let mut travelled = 0.0;
+        while travelled < max_range {
+            let (gx, gy) = match self.cell_at(pose, dx, dy, travelled) {
+                Some(cell) => cell,
+                None => break,
+            };
+
+            if self.get_probability(gx, gy) > OCCUPIED_THRESHOLD {
+                return travelled;
+            }
+
+            travelled += RAY_STEP;
+        }
+
+        max_range
+    }
 }
 
 /// Particle for particle filter
@@ -64,20 +227,146 @@ impl ParticleFilter {
         }
     }
 
-    /// Get estimated pose
+    /// Apply the odometry motion model: add the commanded `delta` plus
+    /// independent Gaussian noise (std devs `noise = (x, y, theta)`) to every
+    /// particle.
+    pub fn predict(&mut self, delta: Pose, noise: (f64, f64, f64)) {
+        let mut rng = rand::thread_rng();
+        let (sigma_x, sigma_y, sigma_theta) = noise;
+
+        for particle in &mut self.particles {
+            particle.pose.x += delta.x + sample_gaussian(&mut rng, sigma_x);
+            particle.pose.y += delta.y + sample_gaussian(&mut rng, sigma_y);
+            particle.pose.theta += delta.theta + sample_gaussian(&mut rng, sigma_theta);
+        }
+    }
+
+    /// Reweight particles by ray-casting their four range beams against the
+    /// occupancy grid and scoring predicted-vs-measured distance with a
+    /// Gaussian likelihood, then resample if the effective sample size has
+    /// dropped too low.
+    pub fn update(&mut self, sensor: &SensorData, grid: &OccupancyGrid) {
+        let max_range = grid.width.max(grid.height) as f64;
+        let measured = [
+            sensor.distance_front,
+            sensor.distance_left,
+            sensor.distance_right,
+            sensor.distance_back,
+        ];
+        let beam_offsets = [0.0, FRAC_PI_2, -FRAC_PI_2, PI];
+
+        for particle in &mut self.particles {
+            let mut likelihood = 1.0;
+
+            for (offset, &meas) in beam_offsets.iter().zip(measured.iter()) {
+                if !meas.is_finite() {
+                    continue;
+                }
+
+                let predicted = grid.cast_ray(&particle.pose, *offset, max_range);
+                let diff = predicted - meas;
+                likelihood *= (-(diff * diff) / (2.0 * SENSOR_SIGMA * SENSOR_SIGMA)).exp();
+            }
+
+            particle.weight *= likelihood;
+        }
+
+        self.normalize_weights();
+
+        if self.effective_sample_size() < self.num_particles as f64 / 2.0 {
+            self.resample();
+        }
+    }
+
+    /// Normalize particle weights to sum to one
+    fn normalize_weights(&mut self) {
+        let total: f64 = self.particles.iter().map(|p| p.weight).sum();
+
+        if total > 0.0 {
+            for particle in &mut self.particles {
+                particle.weight /= total;
+            }
+        } else {
+            let uniform = 1.0 / self.num_particles as f64;
+            for particle in &mut self.particles {
+                particle.weight = uniform;
+            }
+        }
+    }
+
+    /// `1 / Σ wᵢ²` — low when weight has collapsed onto a few particles
+    fn effective_sample_size(&self) -> f64 {
+        let sum_sq: f64 = self.particles.iter().map(|p| p.weight * p.weight).sum();
+        if sum_sq > 0.0 {
+            1.0 / sum_sq
+        } else {
+            0.0
+        }
+    }
+
+    /// Low-variance systematic resampling: draw a single random start
+    /// `r ∈ [0, 1/N)` and step `r + i/N` across the cumulative weight wheel to
+    /// pick N survivors, resetting all weights to `1/N`.
+    fn resample(&mut self) {
+        let n = self.num_particles;
+        let mut rng = rand::thread_rng();
+        let step = 1.0 / n as f64;
+        let start: f64 = rng.gen_range(0.0..step);
+
+        let mut new_particles = Vec::with_capacity(n);
+        let mut cumulative = self.particles[0].weight;
+        let mut i = 0;
+
+        for j in 0..n {
+            let target = start + j as f64 * step;
+            while cumulative < target && i < n - 1 {
+                i += 1;
+                cumulative += self.particles[i].weight;
+            }
+            new_particles.push(self.particles[i]);
+        }
+
+        let uniform = 1.0 / n as f64;
+        for particle in &mut new_particles {
+            particle.weight = uniform;
+        }
+
+        self.particles = new_particles;
+    }
+
+    /// Get estimated pose: weighted mean of x/y, with theta averaged via
+    /// atan2 of the weighted sums of sin/cos (correct for circular quantities).
     pub fn get_estimated_pose(&self) -> Pose {
         let mut x_sum = 0.0;
         let mut y_sum = 0.0;
+        let mut sin_sum = 0.0;
+        let mut cos_sum = 0.0;
 
         for particle in &self.particles {
             x_sum += particle.pose.x * particle.weight;
             y_sum += particle.pose.y * particle.weight;
+            sin_sum += particle.pose.theta.sin() * particle.weight;
+            cos_sum += particle.pose.theta.cos() * particle.weight;
         }
 
-        Pose::new(x_sum, y_sum, 0.0)
+        Pose::new(x_sum, y_sum, sin_sum.atan2(cos_sum))
     }
 }
 
+/// Sample from a zero-mean Gaussian with the given standard deviation via the
+/// Box-Muller transform.
+fn sample_gaussian(rng: &mut impl Rng, std_dev: f64) -> f64 {
+    if std_dev <= 0.0 {
+        return 0.0;
+    }
+
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen_range(0.0..1.0);
+    let z0 = (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos();
+
+    z0 * std_dev
+}
+
 /// Complete SLAM system
 pub struct SLAM {
     pub occupancy_grid: OccupancyGrid,
@@ -122,4 +411,133 @@ mod tests {
         assert_eq!(slam.occupancy_grid.width, 50);
         assert_eq!(slam.particle_filter.num_particles, 100);
     }
+
+    #[test]
+    fn test_predict_moves_particles() {
+        let mut pf = ParticleFilter::new(50);
+        pf.predict(Pose::new(1.0, 0.5, 0.1), (0.0, 0.0, 0.0));
+
+        for particle in &pf.particles {
+            assert!((particle.pose.x - 1.0).abs() < 1e-10);
+            assert!((particle.pose.y - 0.5).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_predict_applies_noise() {
+        let mut pf = ParticleFilter::new(50);
+        pf.predict(Pose::new(0.0, 0.0, 0.0), (1.0, 1.0, 0.1));
+
+        let spread = pf.particles.iter().any(|p| p.pose.x.abs() > 1e-10);
+        assert!(spread);
+    }
+
+    #[test]
+    fn test_update_reweights_and_normalizes() {
+        let mut pf = ParticleFilter::new(20);
+        let grid = OccupancyGrid::new(30, 30, 0.05);
+
+        let sensor = SensorData {
+            distance_front: 5.0,
+            distance_left: 5.0,
+            distance_right: 5.0,
+            distance_back: 5.0,
+            ..SensorData::default()
+        };
+
+        pf.update(&sensor, &grid);
+
+        let total: f64 = pf.particles.iter().map(|p| p.weight).sum();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_effective_sample_size_uniform() {
+        let pf = ParticleFilter::new(10);
+        assert!((pf.effective_sample_size() - 10.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_resample_preserves_count_and_resets_weights() {
+        let mut pf = ParticleFilter::new(10);
+        pf.particles[0].weight = 0.9;
+        for particle in pf.particles.iter_mut().skip(1) {
+            particle.weight = 0.1 / 9.0;
+        }
+
+        pf.resample();
+
+        assert_eq!(pf.particles.len(), 10);
+        for particle in &pf.particles {
+            assert!((particle.weight - 0.1).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_get_estimated_pose_averages_theta() {
+        let mut pf = ParticleFilter::new(2);
+        pf.particles[0].pose = Pose::new(0.0, 0.0, 0.0);
+        pf.particles[0].weight = 0.5;
+        pf.particles[1].pose = Pose::new(0.0, 0.0, 0.0);
+        pf.particles[1].weight = 0.5;
+
+        let estimate = pf.get_estimated_pose();
+        assert!((estimate.theta - 0.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_cast_ray_hits_obstacle() {
+        let mut grid = OccupancyGrid::new(20, 20, 0.05);
+        grid.back_mut()[[10, 15]] = L_MAX;
+        grid.buffer.switch();
+
+        let pose = Pose::new(10.0, 10.0, 0.0);
+        let distance = grid.cast_ray(&pose, 0.0, 20.0);
+
+        assert!(distance < 20.0);
+        assert!((distance - 5.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_cast_ray_max_range_when_clear() {
+        let grid = OccupancyGrid::new(20, 20, 0.05);
+        let pose = Pose::new(10.0, 10.0, 0.0);
+
+        let distance = grid.cast_ray(&pose, 0.0, 5.0);
+        assert_eq!(distance, 5.0);
+    }
+
+    #[test]
+    fn test_new_grid_is_unknown() {
+        let grid = OccupancyGrid::new(10, 10, 0.05);
+        assert!((grid.get_probability(5, 5) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_integrate_scan_marks_hit_occupied_and_path_free() {
+        let mut grid = OccupancyGrid::new(20, 20, 0.05);
+        let pose = Pose::new(5.0, 5.0, 0.0);
+
+        let sensor = SensorData {
+            distance_front: 5.0,
+            ..SensorData::default()
+        };
+
+        for _ in 0..5 {
+            grid.integrate_scan(&pose, &sensor);
+        }
+
+        assert!(grid.get_probability(10, 5) > 0.5);
+        assert!(grid.get_probability(7, 5) < 0.5);
+    }
+
+    #[test]
+    fn test_double_buffer_switch_publishes_writes() {
+        let mut buffer = DoubleBuffer::new((3, 3), 0.0_f32);
+        buffer.back_mut()[[1, 1]] = 9.0;
+
+        assert_eq!(buffer.front()[[1, 1]], 0.0);
+        buffer.switch();
+        assert_eq!(buffer.front()[[1, 1]], 9.0);
+    }
 }
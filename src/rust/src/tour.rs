@@ -0,0 +1,232 @@
+//! Multi-waypoint cleaning-tour optimizer
+//!
+//! Given a handful of spot-clean points or zone centroids, computes a
+//! near-optimal visiting order (minimizing total A* travel) instead of
+//! visiting targets in whatever order they were supplied, then stitches the
+//! per-leg A* paths into one continuous route.
+
+use crate::environment::Environment;
+use crate::pathfinding::AStarPlanner;
+use rayon::prelude::*;
+use rstar::{RTree, RTreeObject, PointDistance, AABB};
+
+#[derive(Debug, Clone, PartialEq)]
+struct TargetPoint {
+    index: usize,
+    coord: [f64; 2],
+}
+
+impl RTreeObject for TargetPoint {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point(self.coord)
+    }
+}
+
+impl PointDistance for TargetPoint {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let dx = self.coord[0] - point[0];
+        let dy = self.coord[1] - point[1];
+        dx * dx + dy * dy
+    }
+}
+
+/// `paths[i][j]` is the A* route from point `i` to point `j`, or `None` when
+/// `j` is unreachable from `i`.
+type PathMatrix = Vec<Vec<Option<Vec<(usize, usize)>>>>;
+
+/// Plans an efficient multi-target cleaning tour starting from the dock
+pub struct TourPlanner<'a> {
+    environment: &'a Environment,
+    dock: (usize, usize),
+}
+
+impl<'a> TourPlanner<'a> {
+    /// Create a planner that starts and seeds its search from `dock`
+    pub fn new(environment: &'a Environment, dock: (usize, usize)) -> Self {
+        Self { environment, dock }
+    }
+
+    /// Compute a near-optimal visiting order for `targets`, returning the
+    /// order as indices into `targets`, the concatenated cell-by-cell route
+    /// starting at the dock, and its total A* travel cost.
+    pub fn plan(&self, targets: &[(usize, usize)]) -> (Vec<usize>, Vec<(usize, usize)>, f64) {
+        if targets.is_empty() {
+            return (Vec::new(), Vec::new(), 0.0);
+        }
+
+        let points: Vec<(usize, usize)> =
+            std::iter::once(self.dock).chain(targets.iter().copied()).collect();
+        let n = points.len();
+
+        // Pairwise A* paths, computed in parallel
+        let paths: PathMatrix = (0..n)
+            .into_par_iter()
+            .map(|i| {
+                let planner = AStarPlanner::new(self.environment);
+                (0..n)
+                    .map(|j| {
+                        if i == j {
+                            Some(vec![points[i]])
+                        } else {
+                            planner.find_path(points[i], points[j], false)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let dist: Vec<Vec<f64>> = paths
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|p| p.as_ref().map(|path| (path.len() - 1) as f64).unwrap_or(f64::INFINITY))
+                    .collect()
+            })
+            .collect();
+
+        let seeded = Self::nearest_neighbor_order(self.dock, targets);
+        let mut route: Vec<usize> = std::iter::once(0).chain(seeded.into_iter().map(|i| i + 1)).collect();
+        Self::two_opt(&mut route, &dist);
+
+        let total_cost = Self::route_cost(&route, &dist);
+        let full_path = Self::stitch_path(&route, &paths);
+        let order = route[1..].iter().map(|&point_index| point_index - 1).collect();
+
+        (order, full_path, total_cost)
+    }
+
+    /// Seed the visiting order via nearest-neighbor, using an R-tree spatial
+    /// index over target coordinates so candidate selection scales past a
+    /// handful of targets instead of scanning every remaining pair.
+    fn nearest_neighbor_order(dock: (usize, usize), targets: &[(usize, usize)]) -> Vec<usize> {
+        let mut tree: RTree<TargetPoint> = RTree::bulk_load(
+            targets
+                .iter()
+                .enumerate()
+                .map(|(index, &(x, y))| TargetPoint { index, coord: [x as f64, y as f64] })
+                .collect(),
+        );
+
+        let mut current = [dock.0 as f64, dock.1 as f64];
+        let mut order = Vec::with_capacity(targets.len());
+
+        while let Some(nearest) = tree.nearest_neighbor(&current).cloned() {
+            current = nearest.coord;
+            order.push(nearest.index);
+            tree.remove(&nearest);
+        }
+
+        order
+    }
+
+    /// Repeatedly reverse the segment between two edges whenever doing so
+    /// shortens the tour, until no improving swap exists. `route[0]` (the
+    /// dock) is never moved, since the tour is an open path, not a cycle.
+    fn two_opt(route: &mut [usize], dist: &[Vec<f64>]) {
+        let n = route.len();
+        if n < 4 {
+            return;
+        }
+
+        let mut improved = true;
+        while improved {
+            improved = false;
+
+            for i in 1..n - 1 {
+                for k in i + 1..n {
+                    let tail_cost = |idx: usize| if idx + 1 < n { dist[route[idx]][route[idx + 1]] } else { 0.0 };
+
+                    let before = dist[route[i - 1]][route[i]] + tail_cost(k);
+                    let after = dist[route[i - 1]][route[k]] + if k + 1 < n {
+                        dist[route[i]][route[k + 1]]
+                    } else {
+                        0.0
+                    };
+
+                    if after + 1e-9 < before {
+                        route[i..=k].reverse();
+                        improved = true;
+                    }
+                }
+            }
+        }
+    }
+
+    fn route_cost(route: &[usize], dist: &[Vec<f64>]) -> f64 {
+        route.windows(2).map(|pair| dist[pair[0]][pair[1]]).sum()
+    }
+
+    fn stitch_path(route: &[usize], paths: &PathMatrix) -> Vec<(usize, usize)> {
+        let mut full_path = Vec::new();
+
+        for pair in route.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let Some(leg) = &paths[a][b] else { continue };
+
+            if full_path.last() == leg.first() {
+                full_path.extend(leg.iter().skip(1));
+            } else {
+                full_path.extend(leg.iter().copied());
+            }
+        }
+
+        full_path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_visits_all_targets_in_a_sensible_order() {
+        let env = Environment::create_empty_room(30, 10);
+        let planner = TourPlanner::new(&env, (1, 5));
+
+        // Laid out so the naive (input) order would backtrack across the room
+        let targets = vec![(20, 5), (5, 5), (25, 5)];
+        let (order, full_path, total_cost) = planner.plan(&targets);
+
+        assert_eq!(order.len(), 3);
+        let visited: std::collections::HashSet<usize> = order.iter().copied().collect();
+        assert_eq!(visited.len(), 3);
+
+        // The optimized order should visit the near target before the far ones
+        assert_eq!(order[0], 1); // (5, 5) is nearest to the dock at (1, 5)
+
+        assert_eq!(full_path[0], (1, 5));
+        assert!(total_cost > 0.0);
+        assert!(full_path.len() as f64 >= total_cost);
+    }
+
+    #[test]
+    fn test_plan_with_no_targets_is_empty() {
+        let env = Environment::create_empty_room(10, 10);
+        let planner = TourPlanner::new(&env, (5, 5));
+
+        let (order, full_path, total_cost) = planner.plan(&[]);
+
+        assert!(order.is_empty());
+        assert!(full_path.is_empty());
+        assert_eq!(total_cost, 0.0);
+    }
+
+    #[test]
+    fn test_two_opt_untangles_a_crossed_route() {
+        let dist = vec![
+            vec![0.0, 1.0, 2.0, 3.0],
+            vec![1.0, 0.0, 3.0, 2.0],
+            vec![2.0, 3.0, 0.0, 1.0],
+            vec![3.0, 2.0, 1.0, 0.0],
+        ];
+        // Deliberately crossed order: 0 -> 2 -> 1 -> 3
+        let mut route = vec![0, 2, 1, 3];
+        TourPlanner::two_opt(&mut route, &dist);
+
+        let initial_cost = 2.0 + 3.0 + 2.0; // dist[0][2] + dist[2][1] + dist[1][3]
+        let cost: f64 = route.windows(2).map(|p| dist[p[0]][p[1]]).sum();
+        assert!(cost < initial_cost);
+    }
+}